@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::time::{Duration, Instant};
+
+use intcode::{get_computer, IntCodeComputer, Signal};
+
+use crate::DAYS;
+
+// stop repeating a benchmark once it has used up this much time, so the slow
+// days still finish in reasonable time
+const TIME_BUDGET: Duration = Duration::from_secs(3);
+
+// how much slower than the baseline a benchmark may get before it's reported
+// as a regression
+const THRESHOLD: f64 = 0.10;
+
+const TICKS: u64 = 1_000_000;
+
+pub struct Sample {
+    pub name: String,
+    pub time: Duration,
+}
+
+fn median(mut times: Vec<Duration>) -> Duration {
+    times.sort();
+    times[times.len() / 2]
+}
+
+// repeats `f` up to `iterations` times after a warm up run, `f` returns the
+// times it measured
+fn repeat<F: FnMut() -> Vec<Duration>>(iterations: usize, mut f: F) -> Vec<Duration> {
+    let n = f().len();
+    let mut runs = vec![vec![]; n];
+
+    let start = Instant::now();
+    for _ in 0..iterations.max(1) {
+        for (run, time) in runs.iter_mut().zip(f()) {
+            run.push(time);
+        }
+        if start.elapsed() > TIME_BUDGET {
+            break;
+        }
+    }
+
+    runs.into_iter().map(median).collect()
+}
+
+pub fn bench_day(day: usize, input: &str, iterations: usize) -> Vec<Sample> {
+    let names = vec![
+        format!("day{}/parse", day),
+        format!("day{}/part1", day),
+        format!("day{}/part2", day),
+    ];
+
+    let times = repeat(iterations, || {
+        let report = DAYS[day - 1](input, None);
+        let mut times = vec![report.parse_time];
+        times.extend(report.answers.iter().map(|a| a.time));
+        times
+    });
+
+    names
+        .into_iter()
+        .zip(times)
+        .map(|(name, time)| Sample { name, time })
+        .collect()
+}
+
+// time per thousand ticks of running `comp` for `TICKS` ticks, feeding it
+// zeros whenever it wants input. a single tick is too short to time on its own
+fn time_ticks(comp: &IntCodeComputer) -> Duration {
+    let mut comp = comp.clone();
+
+    let start = Instant::now();
+    for _ in 0..TICKS {
+        match comp.tick() {
+            Signal::NeedsInput => comp.feed_input(0),
+            Signal::ProducedOutput => {
+                comp.get_output();
+            }
+            Signal::Halt => panic!("benchmark program halted"),
+            _ => {}
+        }
+    }
+    start.elapsed() / (TICKS / 1000) as u32
+}
+
+pub fn bench_intcode(iterations: usize) -> Vec<Sample> {
+    let programs = [
+        // counts down forever, add and jump
+        ("arith", vec![1001, 7, -1, 7, 1105, 1, 0, 0]),
+        // moves the relative base back and forth and writes through it
+        (
+            "relative",
+            vec![109, 1, 21101, 1, 2, 20, 109, -1, 1105, 1, 0],
+        ),
+        // echoes its input
+        ("io", vec![3, 7, 4, 7, 1105, 1, 0, 0]),
+    ];
+
+    programs
+        .iter()
+        .map(|(name, prog)| {
+            let comp = get_computer(prog, vec![]);
+            let times = repeat(iterations, || vec![time_ticks(&comp)]);
+            Sample {
+                name: format!("intcode/1k-ticks-{}", name),
+                time: times[0],
+            }
+        })
+        .collect()
+}
+
+pub fn load_baseline(path: &str) -> Result<HashMap<String, Duration>, Box<dyn Error>> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    let mut baseline = HashMap::new();
+    for line in text.lines().filter(|l| !l.trim().is_empty()) {
+        let mut fields = line.split_whitespace();
+        match (fields.next(), fields.next().map(|x| x.parse::<u64>())) {
+            (Some(name), Some(Ok(nanos))) => {
+                baseline.insert(name.to_string(), Duration::from_nanos(nanos));
+            }
+            _ => return Err(format!("{}: bad line {:?}", path, line).into()),
+        }
+    }
+    Ok(baseline)
+}
+
+pub fn save_baseline(path: &str, samples: &[Sample]) -> Result<(), Box<dyn Error>> {
+    let text: String = samples
+        .iter()
+        .map(|s| format!("{} {}\n", s.name, s.time.as_nanos()))
+        .collect();
+    fs::write(path, text).map_err(|e| format!("{}: {}", path, e))?;
+    Ok(())
+}
+
+// prints the results, compared against `baseline` if given, and returns how
+// many benchmarks regressed
+pub fn print_table(samples: &[Sample], baseline: Option<&HashMap<String, Duration>>) -> usize {
+    let mut regressions = 0;
+
+    println!(
+        "{:<26} {:>12} {:>12} {:>9}",
+        "benchmark", "median", "baseline", "change"
+    );
+    for sample in samples.iter() {
+        let time = format!("{:.2?}", sample.time);
+        let old = match baseline.and_then(|b| b.get(&sample.name)) {
+            Some(old) => old,
+            None => {
+                println!("{:<26} {:>12}", sample.name, time);
+                continue;
+            }
+        };
+
+        let change = sample.time.as_secs_f64() / old.as_secs_f64().max(1e-9) - 1.0;
+        let flag = if change > THRESHOLD {
+            regressions += 1;
+            "  regressed"
+        } else {
+            ""
+        };
+        println!(
+            "{:<26} {:>12} {:>12} {:>+8.1}%{}",
+            sample.name,
+            time,
+            format!("{:.2?}", old),
+            change * 100.0,
+            flag
+        );
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_baseline, print_table, save_baseline, Sample};
+    use std::env;
+    use std::fs;
+    use std::process;
+    use std::time::Duration;
+
+    fn sample(name: &str, micros: u64) -> Sample {
+        Sample {
+            name: name.to_string(),
+            time: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn baselines() {
+        let path = env::temp_dir().join(format!("aoc-baseline-{}", process::id()));
+        let path = path.to_str().unwrap();
+
+        let samples = vec![sample("day1/part1", 100), sample("day1/part2", 2000)];
+        save_baseline(path, &samples).unwrap();
+        let baseline = load_baseline(path).unwrap();
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline["day1/part2"], Duration::from_micros(2000));
+
+        fs::write(path, "day1/part1 fast\n").unwrap();
+        assert!(load_baseline(path).is_err());
+        fs::remove_file(path).unwrap();
+        assert!(load_baseline(path).is_err());
+    }
+
+    #[test]
+    fn counts_regressions() {
+        let baseline = [("a", 100), ("b", 100), ("c", 100)]
+            .iter()
+            .map(|(name, micros)| (name.to_string(), Duration::from_micros(*micros)))
+            .collect();
+
+        // only more than 10% slower counts, and only with a baseline to compare
+        let samples = vec![
+            sample("a", 105),
+            sample("b", 111),
+            sample("c", 50),
+            sample("d", 1000),
+        ];
+        assert_eq!(print_table(&samples, Some(&baseline)), 1);
+        assert_eq!(print_table(&samples, None), 0);
+    }
+}
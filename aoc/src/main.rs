@@ -0,0 +1,395 @@
+mod bench;
+
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::process;
+use std::time::Duration;
+
+use solution::{answers, Report, Runner};
+
+const DAYS: [Runner; 17] = [
+    solution::run::<day1::Day1>,
+    solution::run::<day2::Day2>,
+    solution::run::<day3::Day3>,
+    solution::run::<day4::Day4>,
+    solution::run::<day5::Day5>,
+    solution::run::<day6::Day6>,
+    solution::run::<day7::Day7>,
+    solution::run::<day8::Day8>,
+    solution::run::<day9::Day9>,
+    solution::run::<day10::Day10>,
+    solution::run::<day11::Day11>,
+    solution::run::<day12::Day12>,
+    solution::run::<day13::Day13>,
+    solution::run::<day14::Day14>,
+    solution::run::<day15::Day15>,
+    solution::run::<day16::Day16>,
+    solution::run::<day17::Day17>,
+];
+
+const USAGE: &str = "usage: aoc run <day|all> [options]
+       aoc record <day|all> [options]
+       aoc bench <day|intcode|all> [options]
+
+commands:
+    run                    solve the puzzles and print the answers
+    record                 solve the puzzles and save the answers to dayN/answers
+    bench                  time parsing and each part, and the intcode vm
+
+options:
+    -p, --part <1|2>       only run or record one part
+    -i, --input <path>     read the puzzle input from <path> instead of dayN/input
+
+record options:
+    --force                replace recorded answers that changed, record
+                           refuses to by default
+
+bench options:
+    -n, --iterations <n>   repeat each benchmark up to <n> times (default 10)
+    --save <path>          save the results as a baseline
+    --baseline <path>      compare against a saved baseline, fails on regressions";
+
+#[derive(PartialEq)]
+enum Command {
+    Run,
+    Record,
+    Bench,
+}
+
+struct Options {
+    command: Command,
+    days: Vec<usize>,
+    part: Option<usize>,
+    input: Option<String>,
+    force: bool,
+    intcode: bool,
+    iterations: usize,
+    save: Option<String>,
+    baseline: Option<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, Box<dyn Error>> {
+    let mut args = args.iter();
+
+    let command = match args.next().map(|x| x.as_str()) {
+        Some("run") => Command::Run,
+        Some("record") => Command::Record,
+        Some("bench") => Command::Bench,
+        Some(cmd) => return Err(format!("unknown command {}", cmd).into()),
+        None => return Err("no command given".into()),
+    };
+
+    let mut intcode = false;
+    let days = match args.next().map(|x| x.as_str()) {
+        Some("all") => {
+            intcode = command == Command::Bench;
+            (1..=DAYS.len()).collect()
+        }
+        Some("intcode") if command == Command::Bench => {
+            intcode = true;
+            vec![]
+        }
+        Some(day) => match day.parse::<usize>() {
+            Ok(d) if d >= 1 && d <= DAYS.len() => vec![d],
+            _ => return Err(format!("no solution for day {}", day).into()),
+        },
+        None => return Err("no day given".into()),
+    };
+
+    let mut opts = Options {
+        command,
+        days,
+        part: None,
+        input: None,
+        force: false,
+        intcode,
+        iterations: 10,
+        save: None,
+        baseline: None,
+    };
+
+    while let Some(arg) = args.next() {
+        if arg == "--force" {
+            opts.force = true;
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", arg))?;
+        match arg.as_str() {
+            "-p" | "--part" => match value.as_str() {
+                "1" => opts.part = Some(1),
+                "2" => opts.part = Some(2),
+                _ => return Err(format!("no part {}", value).into()),
+            },
+            "-i" | "--input" => opts.input = Some(value.clone()),
+            "-n" | "--iterations" => {
+                opts.iterations = value
+                    .parse()
+                    .map_err(|_| format!("bad iteration count {}", value))?
+            }
+            "--save" => opts.save = Some(value.clone()),
+            "--baseline" => opts.baseline = Some(value.clone()),
+            _ => return Err(format!("unknown option {}", arg).into()),
+        }
+    }
+
+    if opts.input.is_some() && opts.days.len() > 1 {
+        return Err("--input can only be used when running a single day".into());
+    }
+    if opts.input.is_some() && opts.command == Command::Record {
+        return Err("answers can only be recorded for the default input".into());
+    }
+    if opts.force && opts.command != Command::Record {
+        return Err("--force only applies to record".into());
+    }
+    if opts.command != Command::Bench && (opts.save.is_some() || opts.baseline.is_some()) {
+        return Err("--save and --baseline only apply to bench".into());
+    }
+    if opts.command == Command::Bench && opts.part.is_some() {
+        return Err("bench always times both parts, --part doesn't apply".into());
+    }
+
+    Ok(opts)
+}
+
+fn day_dir(day: usize) -> String {
+    format!("{}/../day{}", env!("CARGO_MANIFEST_DIR"), day)
+}
+
+fn default_input(day: usize) -> String {
+    format!("{}/input", day_dir(day))
+}
+
+fn record_answers(day: usize, report: &Report, force: bool) -> Result<(), Box<dyn Error>> {
+    save_answers(&format!("{}/answers", day_dir(day)), day, report, force)?;
+    println!("  recorded to day{}/answers", day);
+    Ok(())
+}
+
+// saves the answers for the parts in `report` to `path`, failing without
+// saving anything if one differs from what was recorded unless `force` is set
+fn save_answers(
+    path: &str,
+    day: usize,
+    report: &Report,
+    force: bool,
+) -> Result<(), Box<dyn Error>> {
+    let mut recorded = answers::parse(&fs::read_to_string(path).unwrap_or_default());
+    if !force {
+        for answer in report.answers.iter() {
+            if let Some(old) = answers::conflict(&recorded, answer.part, &answer.answer) {
+                return Err(format!(
+                    "day {} part {} was recorded as {} but is now {}, use --force to replace it",
+                    day, answer.part, old, answer.answer
+                )
+                .into());
+            }
+        }
+    }
+    for answer in report.answers.iter() {
+        answers::update(&mut recorded, answer.part, &answer.answer);
+    }
+    fs::write(path, answers::format(&recorded)).map_err(|e| format!("{}: {}", path, e))?;
+    Ok(())
+}
+
+fn print_answer(part: usize, answer: &str, time: Duration) {
+    let timing = format!("({:.2?})", time);
+    if answer.contains('\n') {
+        println!("  Part {}: {}", part, timing);
+        for line in answer.lines() {
+            println!("    {}", line);
+        }
+    } else {
+        println!("  Part {}: {}  {}", part, answer, timing);
+    }
+}
+
+fn run_day(day: usize, opts: &Options) -> Result<Duration, Box<dyn Error>> {
+    let input = read_input(day, opts)?;
+    let report = DAYS[day - 1](&input, opts.part);
+
+    println!("Day {}", day);
+    println!("  Parse: ({:.2?})", report.parse_time);
+    let mut total = report.parse_time;
+    for answer in report.answers.iter() {
+        total += answer.time;
+        print_answer(answer.part, &answer.answer, answer.time);
+    }
+    if opts.command == Command::Record {
+        record_answers(day, &report, opts.force)?;
+    }
+
+    Ok(total)
+}
+
+fn read_input(day: usize, opts: &Options) -> Result<String, Box<dyn Error>> {
+    let path = opts.input.clone().unwrap_or_else(|| default_input(day));
+    Ok(fs::read_to_string(&path).map_err(|e| format!("{}: {}", path, e))?)
+}
+
+// returns false if anything regressed against the baseline
+fn run_bench(opts: &Options) -> Result<bool, Box<dyn Error>> {
+    let baseline = match &opts.baseline {
+        Some(path) => Some(bench::load_baseline(path)?),
+        None => None,
+    };
+
+    let mut samples = vec![];
+    for day in opts.days.iter() {
+        eprintln!("benchmarking day {}", day);
+        samples.extend(bench::bench_day(
+            *day,
+            &read_input(*day, opts)?,
+            opts.iterations,
+        ));
+    }
+    if opts.intcode {
+        eprintln!("benchmarking intcode");
+        samples.extend(bench::bench_intcode(opts.iterations));
+    }
+
+    let regressions = bench::print_table(&samples, baseline.as_ref());
+    if let Some(path) = &opts.save {
+        bench::save_baseline(path, &samples)?;
+        println!("\nsaved baseline to {}", path);
+    }
+    if regressions > 0 {
+        println!("\n{} benchmark(s) regressed", regressions);
+    }
+
+    Ok(regressions == 0)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let opts = match parse_args(&args) {
+        Ok(opts) => opts,
+        Err(e) => {
+            eprintln!("aoc: {}\n\n{}", e, USAGE);
+            process::exit(1);
+        }
+    };
+
+    if opts.command == Command::Bench {
+        match run_bench(&opts) {
+            Ok(true) => return,
+            Ok(false) => process::exit(1),
+            Err(e) => {
+                eprintln!("aoc: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    let mut total = Duration::default();
+    for day in opts.days.iter() {
+        match run_day(*day, &opts) {
+            Ok(time) => total += time,
+            Err(e) => {
+                eprintln!("aoc: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    if opts.days.len() > 1 {
+        println!("\nTotal: {:.2?}", total);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_args, save_answers, Command};
+    use solution::{Answer, Report};
+    use std::env;
+    use std::fs;
+    use std::process;
+    use std::time::Duration;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_args() {
+        let opts = parse_args(&args("run 3 -p 2 --input in.txt")).unwrap();
+        assert!(opts.command == Command::Run);
+        assert_eq!((opts.days, opts.part), (vec![3], Some(2)));
+        assert_eq!(opts.input.as_deref(), Some("in.txt"));
+
+        let opts = parse_args(&args("bench all -n 3 --save base.txt")).unwrap();
+        assert!(opts.command == Command::Bench && opts.intcode);
+        assert_eq!((opts.days.len(), opts.iterations), (17, 3));
+        assert_eq!(opts.save.as_deref(), Some("base.txt"));
+        let opts = parse_args(&args("bench intcode")).unwrap();
+        assert!(opts.days.is_empty() && opts.intcode);
+        assert!(parse_args(&args("record 5 --force")).unwrap().force);
+
+        for bad in [
+            "",
+            "solve 1",
+            "run",
+            "run 0",
+            "run 18",
+            "run intcode",
+            "run 1 -p 3",
+            "run 1 -p",
+            "run 1 --bogus 1",
+            "run all -i in.txt",
+            "record 1 -i in.txt",
+            "run 1 --force",
+            "run 1 --save base.txt",
+            "bench 1 -p 1",
+            "bench 1 -n many",
+        ]
+        .iter()
+        {
+            assert!(parse_args(&args(bad)).is_err(), "{:?} was accepted", bad);
+        }
+    }
+
+    fn report(answers: &[(usize, &str)]) -> Report {
+        Report {
+            parse_time: Duration::default(),
+            answers: answers
+                .iter()
+                .map(|(part, answer)| Answer {
+                    part: *part,
+                    answer: answer.to_string(),
+                    time: Duration::default(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn records_answers() {
+        let path = env::temp_dir().join(format!("aoc-answers-{}", process::id()));
+        let path = path.to_str().unwrap();
+
+        save_answers(path, 1, &report(&[(1, "12"), (2, "34")]), false).unwrap();
+        let saved = fs::read_to_string(path).unwrap();
+        assert_eq!(saved, "== part 1\n12\n== part 2\n34\n");
+
+        // the same answers again or only one part are fine
+        save_answers(path, 1, &report(&[(1, "12"), (2, "34")]), false).unwrap();
+        save_answers(path, 1, &report(&[(2, "34")]), false).unwrap();
+
+        // a changed answer leaves the file alone unless forced
+        let err = save_answers(path, 1, &report(&[(1, "12"), (2, "35")]), false).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("day 1 part 2 was recorded as 34 but is now 35"));
+        assert_eq!(fs::read_to_string(path).unwrap(), saved);
+
+        save_answers(path, 1, &report(&[(2, "35")]), true).unwrap();
+        assert_eq!(
+            fs::read_to_string(path).unwrap(),
+            "== part 1\n12\n== part 2\n35\n"
+        );
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,77 @@
+use solution::Solution;
+use std::error::Error;
+
+fn get_inputs(input: &str) -> Result<Vec<u64>, Box<dyn Error>> {
+    let mut inputs: Vec<u64> = vec![];
+
+    for i in input.lines() {
+        inputs.push(i.trim().parse()?);
+    }
+
+    Ok(inputs)
+}
+
+fn get_mass(i: u64) -> u64 {
+    (i / 3).saturating_sub(2)
+}
+
+fn get_total_mass(i: u64) -> u64 {
+    let mut mass = i;
+    let mut total_fuel = 0;
+    loop {
+        let req_fuel = get_mass(mass);
+        if req_fuel > 0 {
+            total_fuel += req_fuel;
+            mass = req_fuel;
+        } else {
+            break;
+        }
+    }
+
+    total_fuel
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Vec<u64> {
+        match get_inputs(input) {
+            Ok(inp) => inp,
+            _ => panic!("bad input file"),
+        }
+    }
+
+    fn part1(inputs: &Vec<u64>) -> u64 {
+        inputs.iter().map(|x| get_mass(*x)).sum::<u64>()
+    }
+
+    fn part2(inputs: &Vec<u64>) -> u64 {
+        inputs.iter().map(|x| get_total_mass(*x)).sum::<u64>()
+    }
+}
+
+solution::answer_tests!(Day1);
+
+#[cfg(test)]
+mod tests {
+    use super::{get_mass, get_total_mass};
+
+    #[test]
+    fn fuel_for_module() {
+        assert_eq!(get_mass(12), 2);
+        assert_eq!(get_mass(14), 2);
+        assert_eq!(get_mass(1969), 654);
+        assert_eq!(get_mass(100756), 33583);
+    }
+
+    #[test]
+    fn fuel_for_fuel() {
+        assert_eq!(get_total_mass(14), 2);
+        assert_eq!(get_total_mass(1969), 966);
+        assert_eq!(get_total_mass(100756), 50346);
+    }
+}
@@ -0,0 +1,199 @@
+use grid::{Cell, Grid, Point};
+use solution::Solution;
+use std::f64::consts::PI;
+
+// (point, angle, distance)
+type Binned = Vec<Vec<(Point, f64, f64)>>;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Space {
+    Empty,
+    Asteroid,
+}
+
+impl Cell for Space {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Space::Empty),
+            '#' => Some(Space::Asteroid),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Space::Empty => '.',
+            Space::Asteroid => '#',
+        }
+    }
+}
+
+fn get_input(input: &str) -> Grid<Space> {
+    Grid::parse(input.trim())
+}
+
+fn angle_from_y_axis((x, y): Point) -> f64 {
+    // we invert the y-axis here
+    let mut q = (x as f64).atan2(-y as f64);
+    if q < 0.0 {
+        q += 2.0 * PI;
+    }
+    q
+}
+
+fn distance_from_origin((x, y): Point) -> f64 {
+    ((x * x + y * y) as f64).sqrt()
+}
+
+// calculates the angle every point makes with y-axis when origin is shifted to (x0, y0)
+// bins the point on same length and sort the points in each bin by their distance from (x0, y0)
+fn get_binned(inp: &Grid<Space>, (x0, y0): Point) -> Binned {
+    let mut mapped = vec![];
+
+    for ((x, y), v) in inp.iter() {
+        if *v != Space::Asteroid || (x == x0 && y == y0) {
+            continue;
+        }
+
+        // shift the origin to (x0, y0)
+        let (x, y) = (x - x0, y - y0);
+        mapped.push((
+            (x, y),
+            angle_from_y_axis((x, y)),
+            distance_from_origin((x, y)),
+        ));
+    }
+
+    // sort by the angle the make with the y-axis
+    mapped.sort_by(|x, y| (x.1).partial_cmp(&y.1).unwrap());
+
+    let mut i = 0;
+    let n = mapped.len();
+    let mut binned = vec![];
+    while i < n {
+        let mut bin = vec![mapped[i]];
+        i += 1;
+        while i < n && (mapped[i].1 - bin[0].1).abs() < 1e-4 {
+            bin.push(mapped[i]);
+            i += 1;
+        }
+
+        // sort the bin by distance from origin
+        bin.sort_by(|x, y| (x.2).partial_cmp(&y.2).unwrap());
+        binned.push(bin);
+    }
+    binned
+}
+
+fn best_location(inp: &Grid<Space>) -> (usize, Option<Point>) {
+    let mut max_count = usize::MIN;
+    let mut cords = None;
+
+    for (p, v) in inp.iter() {
+        if *v == Space::Empty {
+            continue;
+        }
+        let c = get_binned(inp, p).len();
+        if c > max_count {
+            max_count = c;
+            cords = Some(p);
+        }
+    }
+    (max_count, cords)
+}
+
+fn vaporized_200th(inp: &Grid<Space>, (x0, y0): Point) -> Point {
+    let mut binned = get_binned(inp, (x0, y0));
+
+    let mut count = 1;
+    let mut last_coord = (-1, -1);
+
+    // for the sweeping motion, we continiously loop over the bins and take the
+    // first value from each bin
+    'main_loop: while count <= 200 {
+        for bin in binned.iter_mut() {
+            if !bin.is_empty() {
+                let next = bin.remove(0);
+                last_coord = ((next.0).0 + x0, (next.0).1 + y0);
+                count += 1;
+                if count > 200 {
+                    break 'main_loop;
+                }
+            }
+        }
+    }
+
+    last_coord
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<Space>;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Grid<Space> {
+        get_input(input)
+    }
+
+    fn part1(input: &Grid<Space>) -> usize {
+        let (count, _) = best_location(input);
+        count
+    }
+
+    fn part2(input: &Grid<Space>) -> i64 {
+        let (_, cords) = best_location(input);
+        let (x, y) = vaporized_200th(input, cords.unwrap());
+        x * 100 + y
+    }
+}
+
+solution::answer_tests!(Day10);
+
+#[cfg(test)]
+mod tests {
+    use super::{best_location, get_input, vaporized_200th};
+
+    const LARGE: &str = "
+.#..##.###...#######
+##.############..##.
+.#.######.########.#
+.###.#######.####.#.
+#####.##.#.##.###.##
+..#####..#.#########
+####################
+#.####....###.#.#.##
+##.#################
+#####.##.###..####..
+..######..##.#######
+####.##.####...##..#
+.#####..#.######.###
+##...#.##########...
+#.##########.#######
+.####.#.###.###.#.##
+....##.##.###..#####
+.#.#.###########.###
+#.#.#.#####.####.###
+###.##.####.##.#..##";
+
+    #[test]
+    fn monitoring_station() {
+        let map = get_input(".#..#\n.....\n#####\n....#\n...##");
+        assert_eq!(best_location(&map), (8, Some((3, 4))));
+
+        let map = get_input(
+            "......#.#.\n#..#.#....\n..#######.\n.#.#.###..\n.#..#.....\n\
+             ..#....#.#\n#..#....#.\n.##.#..###\n##...#..#.\n.#....####",
+        );
+        assert_eq!(best_location(&map), (33, Some((5, 8))));
+
+        assert_eq!(best_location(&get_input(LARGE)), (210, Some((11, 13))));
+    }
+
+    #[test]
+    fn vaporization() {
+        let map = get_input(LARGE);
+        assert_eq!(vaporized_200th(&map, (11, 13)), (8, 2));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{Color, Hull};
+use grid::{GifError, Grid, Point, Rgb};
+use std::io::{self, Write};
+
+// black, white and red for the robot
+const PALETTE: [Rgb; 3] = [(0, 0, 0), (255, 255, 255), (255, 0, 0)];
+const ROBOT: u8 = 2;
+
+// the painted hull, black panels drawn black
+pub fn write_pbm<W: Write>(out: &mut W, hull: &Hull, scale: usize) -> io::Result<()> {
+    grid::write_pbm(out, &hull.to_grid(), scale, |c| *c == Color::Black)
+}
+
+// the robot painting, every frame covering everything it painted with the
+// robot in red
+pub fn write_gif<W: Write>(
+    out: W,
+    frames: &[(Hull, Point)],
+    scale: usize,
+    delay: u16,
+) -> Result<(), GifError> {
+    grid::write_gif(out, &indexed(frames), &PALETTE, scale, delay, |i| *i)
+}
+
+// the frames as palette indices, all cropped to the same bounds
+fn indexed(frames: &[(Hull, Point)]) -> Vec<Grid<u8>> {
+    let mut bounds = frames.iter().filter_map(|(hull, _)| hull.bounds());
+    let first = match bounds.next() {
+        Some(first) => first,
+        None => return vec![],
+    };
+    let ((x0, y0), (x1, y1)) = bounds.fold(first, |((x0, y0), (x1, y1)), ((a0, b0), (a1, b1))| {
+        ((x0.min(a0), y0.min(b0)), (x1.max(a1), y1.max(b1)))
+    });
+    let (width, height) = ((x1 - x0 + 1) as usize, (y1 - y0 + 1) as usize);
+
+    frames
+        .iter()
+        .map(|(hull, robot)| {
+            let mut frame = Grid::new(width, height, 0);
+            for y in 0..height as i64 {
+                for x in 0..width as i64 {
+                    let p = (x0 + x, y0 + y);
+                    frame[(x, y)] = if p == *robot {
+                        ROBOT
+                    } else {
+                        hull.color(p) as u8
+                    };
+                }
+            }
+            frame
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{indexed, write_pbm, PALETTE, ROBOT};
+    use crate::{Color, Hull};
+    use grid::Grid;
+
+    fn cells(grid: &Grid<u8>) -> Vec<u8> {
+        grid.iter().map(|(_, i)| *i).collect()
+    }
+
+    #[test]
+    fn images() {
+        let mut hull = Hull::new();
+        hull.paint((0, 0), Color::White);
+        hull.paint((1, 1), Color::White);
+
+        let mut pbm = vec![];
+        write_pbm(&mut pbm, &hull, 1).unwrap();
+        assert_eq!(String::from_utf8(pbm).unwrap(), "P1\n2 2\n0 1\n1 0\n");
+
+        // the robot starts on the top left panel and ends on the bottom right
+        let frames = indexed(&[(Hull::new(), (0, 0)), (hull, (1, 1))]);
+        assert_eq!(frames.len(), 2);
+        let white = Color::White as u8;
+        assert_eq!(cells(&frames[0]), vec![ROBOT, 0, 0, 0]);
+        assert_eq!(cells(&frames[1]), vec![white, 0, 0, ROBOT]);
+
+        // a painting away from the origin has no margin around it
+        let mut hull = Hull::new();
+        hull.paint((5, -3), Color::White);
+        hull.paint((6, -3), Color::Black);
+        let frames = indexed(&[(hull, (6, -3))]);
+        assert_eq!(cells(&frames[0]), vec![white, ROBOT]);
+
+        assert_eq!(PALETTE[Color::Black as usize], (0, 0, 0));
+        assert_eq!(PALETTE[white as usize], (255, 255, 255));
+        assert_eq!(PALETTE[ROBOT as usize], (255, 0, 0));
+    }
+}
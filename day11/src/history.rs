@@ -0,0 +1,124 @@
+use crate::{Color, Hull};
+use grid::{Direction, Point};
+use std::collections::HashSet;
+
+// one coat of paint the robot put on
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Stroke {
+    // how many strokes came before this one, the robot moves once after each
+    pub step: usize,
+    pub at: Point,
+    pub was: Color,
+    pub now: Color,
+    // where the robot was facing while it painted
+    pub heading: Direction,
+}
+
+// everything the robot painted, in order
+#[derive(Clone, Debug)]
+pub struct Painting {
+    // the color of the panel the robot started on
+    pub start: Color,
+    pub strokes: Vec<Stroke>,
+    pub hull: Hull,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Stats {
+    pub strokes: usize,
+    pub panels_painted: usize,
+    // panels that got more than one coat
+    pub repainted: usize,
+    // strokes that changed the color of a panel
+    pub color_changes: usize,
+    pub white_panels: usize,
+}
+
+impl Painting {
+    pub fn stats(&self) -> Stats {
+        Stats {
+            strokes: self.strokes.len(),
+            panels_painted: self.hull.painted(),
+            repainted: self.hull.repainted(),
+            color_changes: self.strokes.iter().filter(|s| s.was != s.now).count(),
+            white_panels: self.hull.count(Color::White),
+        }
+    }
+
+    // the `n` panels with the most coats and how many they got, ties go to
+    // the panel painted first
+    pub fn most_repainted(&self, n: usize) -> Vec<(Point, usize)> {
+        let mut seen = HashSet::new();
+        let mut order = vec![];
+        for stroke in self.strokes.iter() {
+            if self.hull.coats(stroke.at) > 1 && seen.insert(stroke.at) {
+                order.push((stroke.at, self.hull.coats(stroke.at)));
+            }
+        }
+        // a stable sort keeps the order they were first painted in
+        order.sort_by_key(|&(_, coats)| std::cmp::Reverse(coats));
+        order.truncate(n);
+        order
+    }
+
+    // the hull after every `every` strokes and at the end, with where the
+    // robot just painted
+    pub fn frames(&self, every: usize) -> Vec<(Hull, Point)> {
+        let mut hull = Hull::new();
+        hull.set((0, 0), self.start);
+        let mut frames = vec![(hull.clone(), (0, 0))];
+
+        for stroke in self.strokes.iter() {
+            hull.paint(stroke.at, stroke.now);
+            let last = stroke.step + 1 == self.strokes.len();
+            if (stroke.step + 1) % every.max(1) == 0 || last {
+                frames.push((hull.clone(), stroke.at));
+            }
+        }
+        frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Painting, Stroke};
+    use crate::{Color, Hull};
+    use grid::Direction;
+
+    #[test]
+    fn repaints() {
+        let coats = [(0, 0), (1, 0), (0, 0), (1, 0), (0, 0), (2, 2)];
+        let mut hull = Hull::new();
+        let mut strokes = vec![];
+        for (step, at) in coats.iter().enumerate() {
+            let was = hull.color(*at);
+            let now = if step < 3 { Color::White } else { Color::Black };
+            hull.paint(*at, now);
+            strokes.push(Stroke {
+                step,
+                at: *at,
+                was,
+                now,
+                heading: Direction::Up,
+            });
+        }
+
+        let painting = Painting {
+            start: Color::Black,
+            strokes,
+            hull,
+        };
+        let stats = painting.stats();
+        assert_eq!(
+            (stats.strokes, stats.panels_painted, stats.repainted),
+            (6, 3, 2)
+        );
+        assert_eq!(stats.color_changes, 4);
+        assert_eq!(painting.most_repainted(5), vec![((0, 0), 3), ((1, 0), 2)]);
+
+        let frames = painting.frames(4);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[2].1, (2, 2));
+        assert_eq!(frames[2].0.to_string(), painting.hull.to_string());
+    }
+}
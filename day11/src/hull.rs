@@ -0,0 +1,149 @@
+use crate::Color;
+use grid::{Grid, Point, SparseGrid};
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Panel {
+    color: Color,
+    // how many times the robot painted it
+    coats: usize,
+}
+
+// the side of the ship, it goes on as far as the robot wants in every
+// direction. panels nobody touched are black.
+#[derive(Clone, Debug, Default)]
+pub struct Hull {
+    panels: SparseGrid<Panel>,
+}
+
+impl Hull {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn color(&self, p: Point) -> Color {
+        self.panels.get(p).map_or(Color::Black, |panel| panel.color)
+    }
+
+    // colors a panel without the robot painting it
+    pub fn set(&mut self, p: Point, color: Color) {
+        match self.panels.get_mut(p) {
+            Some(panel) => panel.color = color,
+            None => {
+                self.panels.insert(p, Panel { color, coats: 0 });
+            }
+        }
+    }
+
+    pub fn paint(&mut self, p: Point, color: Color) {
+        self.set(p, color);
+        self.panels.get_mut(p).unwrap().coats += 1;
+    }
+
+    // how many panels are `color`, counting only the ones that were touched
+    pub fn count(&self, color: Color) -> usize {
+        self.panels
+            .iter()
+            .filter(|(_, panel)| panel.color == color)
+            .count()
+    }
+
+    // top left and bottom right corners of every panel that was touched
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.panels.bounds()
+    }
+
+    // how many times the robot painted a panel
+    pub fn coats(&self, p: Point) -> usize {
+        self.panels.get(p).map_or(0, |panel| panel.coats)
+    }
+
+    // how many panels were painted at least once
+    pub fn painted(&self) -> usize {
+        self.painted_over(0)
+    }
+
+    // how many panels were painted more than once
+    pub fn repainted(&self) -> usize {
+        self.painted_over(1)
+    }
+
+    fn painted_over(&self, coats: usize) -> usize {
+        self.panels
+            .iter()
+            .filter(|(_, panel)| panel.coats > coats)
+            .count()
+    }
+
+    // the smallest rectangle holding every panel the robot touched, as a grid
+    // with (0, 0) in its top left corner
+    pub fn to_grid(&self) -> Grid<Color> {
+        self.crop(|_| true)
+    }
+
+    // like `to_grid` but cropped to the white panels, which is all there is
+    // to the registration identifier
+    pub fn white_grid(&self) -> Grid<Color> {
+        self.crop(|panel| panel.color == Color::White)
+    }
+
+    fn crop<F: Fn(&Panel) -> bool>(&self, keep: F) -> Grid<Color> {
+        let kept: Vec<(Point, Color)> = self
+            .panels
+            .iter()
+            .filter(|(_, panel)| keep(panel))
+            .map(|(p, panel)| (p, panel.color))
+            .collect();
+        if kept.is_empty() {
+            return Grid::new(0, 0, Color::Black);
+        }
+
+        let x0 = kept.iter().map(|(p, _)| p.0).min().unwrap();
+        let y0 = kept.iter().map(|(p, _)| p.1).min().unwrap();
+        let x1 = kept.iter().map(|(p, _)| p.0).max().unwrap();
+        let y1 = kept.iter().map(|(p, _)| p.1).max().unwrap();
+
+        let width = (x1 - x0 + 1) as usize;
+        let height = (y1 - y0 + 1) as usize;
+        let mut grid = Grid::new(width, height, Color::Black);
+        for ((x, y), color) in kept {
+            grid[(x - x0, y - y0)] = color;
+        }
+        grid
+    }
+}
+
+// everything the robot touched, cropped to fit it
+impl fmt::Display for Hull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_grid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hull;
+    use crate::Color;
+
+    #[test]
+    fn grows_and_crops() {
+        let mut hull = Hull::new();
+        hull.set((0, 0), Color::White);
+        hull.paint((-3, -2), Color::White);
+        hull.paint((-2, -2), Color::Black);
+        hull.paint((-2, -2), Color::White);
+        hull.paint((5, 5), Color::Black);
+
+        assert_eq!(hull.color((-2, -2)), Color::White);
+        assert_eq!(hull.color((100, -100)), Color::Black);
+        assert_eq!(hull.painted(), 3);
+        assert_eq!(hull.repainted(), 1);
+        assert_eq!((hull.coats((-2, -2)), hull.coats((0, 0))), (2, 0));
+        assert_eq!(hull.white_grid().to_string(), "##  \n    \n   #\n");
+
+        // the black panel in the corner still counts for the picture
+        let grid = hull.to_grid();
+        assert_eq!((grid.width(), grid.height()), (9, 8));
+        assert_eq!(hull.to_string().lines().next(), Some("##       "));
+    }
+}
@@ -0,0 +1,163 @@
+mod export;
+mod history;
+mod hull;
+
+pub use export::{write_gif, write_pbm};
+pub use history::{Painting, Stats, Stroke};
+pub use hull::Hull;
+
+use grid::{read_letters, Axes, Cell, Direction, Point, Turn};
+use intcode::{get_computer, parse_program, IntCodeComputer, Signal};
+use solution::Solution;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Color {
+    Black = 0,
+    White = 1,
+}
+
+impl Color {
+    fn from_int(i: i64) -> Color {
+        match i {
+            0 => Color::Black,
+            1 => Color::White,
+            _ => panic!("unknown color {}", i),
+        }
+    }
+}
+
+impl Cell for Color {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            ' ' => Some(Color::Black),
+            '#' => Some(Color::White),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Color::Black => ' ',
+            Color::White => '#',
+        }
+    }
+}
+
+struct Ehpr {
+    loc: Point,
+    dir: Direction,
+    hull: Hull,
+    strokes: Vec<Stroke>,
+    comp: IntCodeComputer,
+}
+
+impl Ehpr {
+    // starts at (0, 0) on a panel of `start` color
+    fn new(input: &[i64], start: Color) -> Self {
+        let mut hull = Hull::new();
+        hull.set((0, 0), start);
+        Ehpr {
+            loc: (0, 0),
+            dir: Direction::Up,
+            hull,
+            strokes: vec![],
+            comp: get_computer(input, vec![]),
+        }
+    }
+
+    fn turn_and_move(&mut self, code: i64) {
+        let turn = Turn::from_robot_code(code)
+            .unwrap_or_else(|| panic!("dont know how to turn in direction {}", code));
+        self.dir = self.dir.turn(turn);
+        self.loc = self.dir.step(self.loc, Axes::YDown);
+    }
+
+    fn tick(&mut self) -> bool {
+        match self.comp.run() {
+            Signal::Halt => return false, // the robot is done
+            Signal::NeedsInput => self.comp.feed_input(self.hull.color(self.loc) as i64),
+            Signal::ProducedOutput => {
+                let paint_color = Color::from_int(self.comp.get_output().unwrap());
+                self.strokes.push(Stroke {
+                    step: self.strokes.len(),
+                    at: self.loc,
+                    was: self.hull.color(self.loc),
+                    now: paint_color,
+                    heading: self.dir,
+                });
+                self.hull.paint(self.loc, paint_color);
+
+                // the program should produce another output for direction now
+                self.comp.run_till_signal(Signal::ProducedOutput);
+                let direction = self.comp.get_output().unwrap();
+                self.turn_and_move(direction);
+            }
+            _ => panic!("this shouldn't happen"),
+        }
+
+        // continue execution
+        true
+    }
+}
+
+// lets the robot paint until its program halts, starting on a panel of
+// `start` color
+pub fn paint(inp: &[i64], start: Color) -> Painting {
+    let mut robot = Ehpr::new(inp, start);
+    while robot.tick() {}
+    Painting {
+        start,
+        strokes: robot.strokes,
+        hull: robot.hull,
+    }
+}
+
+fn count_painted(inp: &[i64]) -> i64 {
+    paint(inp, Color::Black).hull.painted() as i64
+}
+
+fn paint_registration(inp: &[i64]) -> String {
+    let hull = paint(inp, Color::White).hull;
+    read_letters(&hull.white_grid(), |c| *c == Color::White)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Vec<i64> {
+        parse_program(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> i64 {
+        count_painted(input)
+    }
+
+    fn part2(input: &Vec<i64>) -> String {
+        paint_registration(input)
+    }
+}
+
+solution::answer_tests!(Day11);
+
+#[cfg(test)]
+mod tests {
+    use super::count_painted;
+
+    #[test]
+    fn example_moves() {
+        // outputs the paint and turn instructions from the puzzle without
+        // looking at the camera
+        let moves = [(1, 0), (0, 0), (1, 0), (1, 0), (0, 1), (1, 0), (1, 0)];
+        let mut prog = vec![];
+        for (color, turn) in moves.iter() {
+            prog.extend_from_slice(&[104, *color, 104, *turn]);
+        }
+        prog.push(99);
+
+        assert_eq!(count_painted(&prog), 6);
+    }
+}
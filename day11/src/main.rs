@@ -1,169 +1,136 @@
-use intcode::{get_computer, IntCodeComputer, Signal};
+use day11::Color;
+use solution::Solution;
+use std::env;
 use std::error::Error;
-use std::fs::File;
-use std::io::prelude::*;
-
-use std::collections::HashSet;
-
-const BLACK: i64 = 0;
-const WHITE: i64 = 1;
-
-const LEFT: i64 = 0;
-const RIGHT: i64 = 1;
-
-fn get_input() -> Result<Vec<i64>, Box<dyn Error>> {
-    let mut f = File::open("input")?;
-    let mut s = String::new();
-    f.read_to_string(&mut s)?;
-
-    Ok(s.split(',').filter_map(|x| x.parse::<i64>().ok()).collect())
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::process;
+
+const USAGE: &str = "usage: day11 [input] [options]
+
+options:
+    --start <color>        color of the panel the robot starts on for the
+                           stats and images: black or white (default white)
+    --stats                show how the robot painted the hull
+    --pbm <path>           save the painted hull as a PBM image
+    --gif <path>           save the robot painting as an animated GIF
+    --every <n>            strokes between GIF frames (default 10)";
+
+// size of a panel in the saved images
+const IMAGE_SCALE: usize = 8;
+
+// hundredths of a second between GIF frames
+const GIF_DELAY: u16 = 4;
+
+// how many of the most repainted panels --stats lists
+const MOST_REPAINTED: usize = 5;
+
+struct Options {
+    input: String,
+    start: Color,
+    stats: bool,
+    pbm: Option<String>,
+    gif: Option<String>,
+    every: usize,
 }
 
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-struct EHPR {
-    loc: (i64, i64),
-    dir: Direction,
-    board: Vec<Vec<i64>>,
-    comp: IntCodeComputer,
-}
-
-impl EHPR {
-    fn new(input: &Vec<i64>, (height, width): (usize, usize)) -> Self {
-        EHPR {
-            loc: (0, 0),
-            dir: Direction::Up,
-            board: vec![vec![BLACK; width]; height],
-            comp: get_computer(&input, vec![]),
+fn parse_args(args: &[String]) -> Result<Options, Box<dyn Error>> {
+    let mut opts = Options {
+        input: concat!(env!("CARGO_MANIFEST_DIR"), "/input").to_string(),
+        start: Color::White,
+        stats: false,
+        pbm: None,
+        gif: None,
+        every: 10,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--stats" => opts.stats = true,
+            "--start" | "--pbm" | "--gif" | "--every" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", arg))?
+                    .clone();
+                match arg.as_str() {
+                    "--start" => {
+                        opts.start = match value.as_str() {
+                            "black" => Color::Black,
+                            "white" => Color::White,
+                            _ => return Err(format!("unknown color {}", value).into()),
+                        }
+                    }
+                    "--pbm" => opts.pbm = Some(value),
+                    "--gif" => opts.gif = Some(value),
+                    _ => {
+                        opts.every = match value.parse() {
+                            Ok(n) if n > 0 => n,
+                            _ => return Err(format!("bad frame interval {}", value).into()),
+                        }
+                    }
+                }
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg).into()),
+            _ => opts.input = arg.clone(),
         }
     }
 
-    fn set_location(&mut self, loc: (i64, i64)) {
-        self.loc = loc
-    }
-
-    fn paint_board(&mut self, color: i64) {
-        let (x, y) = self.loc;
-        self.board[y as usize][x as usize] = color;
-    }
+    Ok(opts)
+}
 
-    fn get_color(&self) -> i64 {
-        let (x, y) = self.loc;
-        self.board[y as usize][x as usize]
+// paints the hull again from the chosen panel and shows or saves what was
+// asked for
+fn inspect(input: &[i64], opts: &Options) -> Result<(), Box<dyn Error>> {
+    if !opts.stats && opts.pbm.is_none() && opts.gif.is_none() {
+        return Ok(());
     }
-
-    fn turn_right(&mut self) {
-        use Direction::*;
-        self.dir = match self.dir {
-            Up => Right,
-            Down => Left,
-            Left => Up,
-            Right => Down,
+    let painting = day11::paint(input, opts.start);
+
+    if opts.stats {
+        let stats = painting.stats();
+        println!(
+            "{} strokes on {} panels, {} painted more than once, {} changed color, {} white",
+            stats.strokes,
+            stats.panels_painted,
+            stats.repainted,
+            stats.color_changes,
+            stats.white_panels
+        );
+        for ((x, y), coats) in painting.most_repainted(MOST_REPAINTED) {
+            println!("  ({}, {}) got {} coats", x, y, coats);
         }
     }
-
-    fn turn_left(&mut self) {
-        use Direction::*;
-        self.dir = match self.dir {
-            Up => Left,
-            Down => Right,
-            Left => Down,
-            Right => Up,
-        }
+    if let Some(path) = &opts.pbm {
+        day11::write_pbm(
+            &mut BufWriter::new(File::create(path)?),
+            &painting.hull,
+            IMAGE_SCALE,
+        )?;
     }
-
-    fn turn(&mut self, dir: i64) {
-        if dir == LEFT {
-            self.turn_left()
-        } else if dir == RIGHT {
-            self.turn_right();
-        } else {
-            panic!("dont know how to turn in direction {}", dir);
-        }
-    }
-
-    fn move_forward(&mut self) {
-        use Direction::*;
-        let (x, y) = self.loc;
-        self.loc = match self.dir {
-            Up => (x, y - 1),
-            Down => (x, y + 1),
-            Right => (x + 1, y),
-            Left => (x - 1, y),
-        }
-    }
-
-    fn turn_and_move(&mut self, dir: i64) {
-        self.turn(dir);
-        self.move_forward();
-    }
-
-    fn tick(&mut self) -> bool {
-        match self.comp.run() {
-            Signal::Halt => return false, // the robot is done
-            Signal::NeedsInput => self.comp.feed_input(self.get_color()),
-            Signal::ProducedOutput => {
-                let paint_color = self.comp.get_output().unwrap();
-                self.paint_board(paint_color);
-
-                // the program should produce another output for direction now
-                self.comp.run_till_signal(Signal::ProducedOutput);
-                let direction = self.comp.get_output().unwrap();
-                self.turn_and_move(direction);
-            }
-            _ => panic!("this shouldn't happen"),
-        }
-
-        // continue execution
-        true
-    }
-
-    fn print_board(&self) {
-        for row in self.board.iter() {
-            for j in row {
-                print!("{}", if *j == BLACK { ' ' } else { '#' });
-            }
-            println!("");
-        }
+    if let Some(path) = &opts.gif {
+        let out = BufWriter::new(File::create(path)?);
+        let frames = painting.frames(opts.every);
+        day11::write_gif(out, &frames, IMAGE_SCALE, GIF_DELAY)?;
     }
+    Ok(())
 }
 
-fn part1(inp: &Vec<i64>) -> i64 {
-    let mut hs: HashSet<(i64, i64)> = HashSet::new();
-    let mut count: i64 = 0;
-
-    // 101x101 was found by keeping track of robot movements on a much bigger board
-    // then trimming to the required size
-    let mut robot = EHPR::new(&inp, (101, 101));
-    robot.set_location((50, 50));
-
-    while robot.tick() {
-        if !hs.contains(&robot.loc) {
-            hs.insert(robot.loc);
-            count += 1;
-        }
-    }
-    count
-}
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let opts = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}\n\n{}", e, USAGE);
+        process::exit(1);
+    });
 
-fn part2(inp: &Vec<i64>) {
-    // let board size to 6x45
-    // this was found by doing a run on much bigger board
-    let mut robot = EHPR::new(&inp, (6, 45));
-    robot.board[0][0] = WHITE;
-    while robot.tick() {}
-    robot.print_board();
-}
+    let raw = fs::read_to_string(&opts.input).unwrap();
+    let input = day11::Day11::parse(&raw);
 
-fn main() {
-    let input: Vec<i64> = get_input().unwrap();
+    println!("Part 1: {}", day11::Day11::part1(&input));
+    println!("Part 2: {}", day11::Day11::part2(&input));
 
-    println!("Part1: {:?}", part1(&input));
-    println!("Part2:");
-    part2(&input);
+    if let Err(e) = inspect(&input, &opts) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,243 @@
+use solution::Solution;
+use std::cmp::{max, min};
+use std::convert::TryInto;
+
+// https://rosettacode.org/wiki/Least_common_multiple
+fn gcd(a: u64, b: u64) -> u64 {
+    match ((a, b), (a & 1, b & 1)) {
+        ((x, y), _) if x == y => y,
+        ((0, x), _) | ((x, 0), _) => x,
+        ((x, y), (0, 1)) | ((y, x), (1, 0)) => gcd(x >> 1, y),
+        ((x, y), (0, 0)) => gcd(x >> 1, y >> 1) << 1,
+        ((x, y), (1, 1)) => {
+            let (x, y) = (min(x, y), max(x, y));
+            gcd((y - x) >> 1, x)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a * b / gcd(a, b)
+}
+
+// a point or a velocity, one coordinate per dimension
+type Vector<const D: usize> = [i64; D];
+
+#[derive(Clone)]
+struct Body<const D: usize> {
+    position: Vector<D>,
+    velocity: Vector<D>,
+}
+
+impl<const D: usize> Body<D> {
+    fn with_position(position: Vector<D>) -> Self {
+        Body {
+            position,
+            velocity: [0; D],
+        }
+    }
+
+    fn potential_energy(&self) -> i64 {
+        self.position.iter().map(|p| p.abs()).sum()
+    }
+
+    fn kinetic_energy(&self) -> i64 {
+        self.velocity.iter().map(|v| v.abs()).sum()
+    }
+
+    fn total_energy(&self) -> i64 {
+        self.potential_energy() * self.kinetic_energy()
+    }
+
+    fn update_axis_position(&mut self, axis: usize) {
+        self.position[axis] += self.velocity[axis];
+    }
+}
+
+// any number of bodies pulling on each other in `D` dimensions
+#[derive(Clone)]
+pub struct System<const D: usize> {
+    bodies: Vec<Body<D>>,
+    _initial_state: Vec<Body<D>>,
+}
+
+impl<const D: usize> System<D> {
+    fn new(bodies: Vec<Body<D>>) -> Self {
+        System {
+            _initial_state: bodies.clone(),
+            bodies,
+        }
+    }
+
+    fn update_axis_velocities(&mut self, i: usize, j: usize, axis: usize) {
+        let pull = self.bodies[j].position[axis] - self.bodies[i].position[axis];
+        self.bodies[i].velocity[axis] += pull.signum();
+        self.bodies[j].velocity[axis] -= pull.signum();
+    }
+
+    fn update_axis_positions(&mut self, axis: usize) {
+        self.bodies
+            .iter_mut()
+            .for_each(|body| body.update_axis_position(axis));
+    }
+
+    fn total_energy(&self) -> i64 {
+        self.bodies.iter().map(|b| b.total_energy()).sum()
+    }
+
+    fn step_along_axis(&mut self, axis: usize) {
+        for i in 0..self.bodies.len() {
+            for j in (i + 1)..self.bodies.len() {
+                self.update_axis_velocities(i, j, axis);
+            }
+        }
+        self.update_axis_positions(axis);
+    }
+
+    fn step(&mut self, num: usize) {
+        for _ in 0..num {
+            for axis in 0..D {
+                self.step_along_axis(axis);
+            }
+        }
+    }
+
+    fn bodies_in_initial_position(&self, axis: usize) -> bool {
+        self.bodies
+            .iter()
+            .zip(&self._initial_state)
+            .all(|(body, og)| body.position[axis] == og.position[axis] && body.velocity[axis] == 0)
+    }
+
+    fn get_cycle_length(&mut self, axis: usize) -> u64 {
+        self.step_along_axis(axis);
+        let mut counter: u64 = 1;
+        while !self.bodies_in_initial_position(axis) {
+            self.step_along_axis(axis);
+            counter += 1;
+        }
+
+        counter
+    }
+
+    // the axes move independently, so the whole system repeats once every
+    // axis does
+    fn get_first_repeating_state(&mut self) -> u64 {
+        (0..D).fold(1, |cycle, axis| lcm(cycle, self.get_cycle_length(axis)))
+    }
+}
+
+// one body per line like `<x=-1, y=0, z=2>`, with exactly `D` coordinates
+fn get_input<const D: usize>(input: &str) -> System<D> {
+    fn get_body_from_line<const D: usize>(line: &str) -> Body<D> {
+        let coords = line
+            .split(", ")
+            .map(|x| x.split('=').nth(1).unwrap().parse::<i64>().unwrap())
+            .collect::<Vec<i64>>();
+        let position = coords
+            .as_slice()
+            .try_into()
+            .unwrap_or_else(|_| panic!("expected {} coordinates in <{}>", D, line));
+        Body::with_position(position)
+    }
+
+    let bodies = input
+        .trim()
+        .split('\n')
+        .map(|line| get_body_from_line(&line[1..(line.len() - 1)]))
+        .collect::<Vec<Body<D>>>();
+    System::new(bodies)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = System<3>;
+    type Part1 = i64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> System<3> {
+        get_input(input)
+    }
+
+    fn part1(sys: &System<3>) -> i64 {
+        let mut sys = sys.clone();
+        sys.step(1000);
+        sys.total_energy()
+    }
+
+    fn part2(sys: &System<3>) -> u64 {
+        sys.clone().get_first_repeating_state()
+    }
+}
+
+solution::answer_tests!(Day12);
+
+#[cfg(test)]
+mod tests {
+    use super::{get_input, System};
+
+    const FIRST: &str = "<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n<x=4, y=-8, z=8>\n<x=3, y=5, z=-1>";
+    const SECOND: &str =
+        "<x=-8, y=-10, z=0>\n<x=5, y=5, z=10>\n<x=2, y=-7, z=3>\n<x=9, y=-8, z=-3>";
+
+    #[test]
+    fn energy() {
+        let mut sys: System<3> = get_input(FIRST);
+        sys.step(10);
+        assert_eq!(sys.total_energy(), 179);
+
+        let mut sys: System<3> = get_input(SECOND);
+        sys.step(100);
+        assert_eq!(sys.total_energy(), 1940);
+    }
+
+    #[test]
+    fn repeating_state() {
+        assert_eq!(get_input::<3>(FIRST).get_first_repeating_state(), 2772);
+        assert_eq!(
+            get_input::<3>(SECOND).get_first_repeating_state(),
+            4686774924
+        );
+    }
+
+    #[test]
+    fn two_dimensions() {
+        // two bodies meet in the middle, pass each other, stop and come back
+        let mut sys: System<2> = get_input("<x=0, y=0>\n<x=2, y=0>");
+        sys.step(1);
+        assert_eq!(sys.total_energy(), 2);
+        sys.step(1);
+        assert_eq!(sys.total_energy(), 2);
+        // they stop where the other one started
+        sys.step(1);
+        assert_eq!(sys.total_energy(), 0);
+        assert_eq!(
+            get_input::<2>("<x=0, y=0>\n<x=2, y=0>").get_first_repeating_state(),
+            6
+        );
+
+        // the first example without z
+        let flat = "<x=-1, y=0>\n<x=2, y=-10>\n<x=4, y=-8>\n<x=3, y=5>";
+        assert_eq!(get_input::<2>(flat).get_first_repeating_state(), 252);
+    }
+
+    #[test]
+    fn four_dimensions() {
+        // a fourth axis where nothing moves changes neither the energy nor
+        // when the system repeats
+        let still = "<x=-1, y=0, z=2, w=0>\n<x=2, y=-10, z=-7, w=0>\n<x=4, y=-8, z=8, w=0>\n<x=3, y=5, z=-1, w=0>";
+        let mut sys: System<4> = get_input(still);
+        sys.step(10);
+        assert_eq!(sys.total_energy(), 179);
+        assert_eq!(get_input::<4>(still).get_first_repeating_state(), 2772);
+
+        // five bodies, with a w axis that repeats at its own pace
+        let five = "<x=-1, y=0, z=2, w=1>\n<x=2, y=-10, z=-7, w=-3>\n<x=4, y=-8, z=8, w=0>\n<x=3, y=5, z=-1, w=2>\n<x=0, y=0, z=0, w=0>";
+        let cycle = get_input::<4>(five).get_first_repeating_state();
+        let mut again = get_input::<4>(five);
+        again.step(cycle as usize);
+        assert!((0..4).all(|axis| again.bodies_in_initial_position(axis)));
+    }
+}
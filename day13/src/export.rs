@@ -0,0 +1,106 @@
+use crate::{Screen, Tile};
+use grid::{GifError, Grid, Rgb};
+use std::io::{self, Write};
+
+// the color of places nothing was drawn yet
+const BLANK: Rgb = (0, 0, 0);
+
+const TILES: [Tile; 5] = [
+    Tile::Empty,
+    Tile::Wall,
+    Tile::Block,
+    Tile::Paddle,
+    Tile::Ball,
+];
+
+// the size in cells of the area covered by all the frames
+fn size(frames: &[Screen]) -> (usize, usize) {
+    frames
+        .iter()
+        .filter_map(|f| f.bounds())
+        .fold((0, 0), |(w, h), (_, (x, y))| {
+            (w.max(x as usize + 1), h.max(y as usize + 1))
+        })
+}
+
+// the tiles of the frame from the top left corner, None where nothing was
+// drawn yet
+fn tiles(frame: &Screen, (width, height): (usize, usize)) -> Grid<Option<Tile>> {
+    let mut grid = Grid::new(width, height, None);
+    for y in 0..height as i64 {
+        for x in 0..width as i64 {
+            grid[(x, y)] = frame.get((x, y));
+        }
+    }
+    grid
+}
+
+// blank first, then the tiles in id order
+fn palette() -> Vec<Rgb> {
+    let mut colors = vec![BLANK];
+    colors.extend(TILES.iter().map(|t| t.color()));
+    colors
+}
+
+fn index(tile: &Option<Tile>) -> u8 {
+    tile.map_or(0, |t| t.id() as u8 + 1)
+}
+
+pub fn write_ppm<W: Write>(out: &mut W, frame: &Screen, scale: usize) -> io::Result<()> {
+    let grid = tiles(frame, size(std::slice::from_ref(frame)));
+    grid::write_ppm(out, &grid, scale, |t| t.map_or(BLANK, |t| t.color()))
+}
+
+pub fn write_gif<W: Write>(
+    out: W,
+    frames: &[Screen],
+    scale: usize,
+    delay: u16,
+) -> Result<(), GifError> {
+    let size = size(frames);
+    let frames: Vec<_> = frames.iter().map(|f| tiles(f, size)).collect();
+    grid::write_gif(out, &frames, &palette(), scale, delay, index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{index, palette, size, tiles, write_ppm};
+    use crate::{Screen, Tile};
+
+    #[test]
+    fn images() {
+        let mut frame = Screen::new();
+        frame.set((0, 0), Tile::Wall);
+        frame.set((2, 1), Tile::Ball);
+
+        let mut ppm = vec![];
+        write_ppm(&mut ppm, &frame, 2).unwrap();
+        assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 6 * 4 * 3);
+        // the wall in the top left corner is white
+        assert_eq!(ppm[11..14], [255, 255, 255]);
+    }
+
+    #[test]
+    fn gif_frames() {
+        let mut frame = Screen::new();
+        frame.set((0, 0), Tile::Wall);
+        frame.set((2, 1), Tile::Ball);
+
+        // every frame covers the biggest one, from the top left corner
+        let frames = [Screen::new(), frame];
+        assert_eq!(size(&frames), (3, 2));
+        let grid = tiles(&frames[1], (3, 2));
+        assert_eq!(grid[(0, 0)], Some(Tile::Wall));
+        assert_eq!(grid[(1, 0)], None);
+        assert!(tiles(&frames[0], (3, 2)).iter().all(|(_, t)| t.is_none()));
+
+        // blank first, then the tiles in id order
+        let colors = palette();
+        assert_eq!(colors.len(), 6);
+        assert_eq!(colors[index(&None) as usize], (0, 0, 0));
+        for tile in [Tile::Wall, Tile::Ball].iter() {
+            assert_eq!(colors[index(&Some(*tile)) as usize], tile.color());
+        }
+    }
+}
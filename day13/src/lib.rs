@@ -0,0 +1,343 @@
+mod export;
+mod record;
+mod render;
+mod screen;
+#[cfg(feature = "sdl")]
+mod sdl;
+mod strategy;
+
+pub use export::{write_gif, write_ppm};
+pub use record::{Event, Recording};
+pub use render::{Headless, Renderer, Terminal};
+pub use screen::{Change, Screen};
+#[cfg(feature = "sdl")]
+pub use sdl::{SdlKeyboard, SdlWindow};
+pub use strategy::{Predictive, Strategy, TerminalKeyboard, Tracker};
+
+use grid::Cell;
+use intcode::{get_computer, parse_program, Signal};
+use solution::Solution;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl Tile {
+    fn from_id(id: i64) -> Self {
+        Self::try_from_id(id).unwrap_or_else(|| panic!("unexpected tile id {}", id))
+    }
+
+    fn try_from_id(id: i64) -> Option<Self> {
+        use Tile::*;
+        match id {
+            0 => Some(Empty),
+            1 => Some(Wall),
+            2 => Some(Block),
+            3 => Some(Paddle),
+            4 => Some(Ball),
+            _ => None,
+        }
+    }
+
+    pub fn id(&self) -> i64 {
+        *self as i64
+    }
+
+    // red, green and blue
+    pub fn color(&self) -> (u8, u8, u8) {
+        use Tile::*;
+        match self {
+            Empty => (85, 85, 85),
+            Wall => (255, 255, 255),
+            Block => (255, 61, 61),
+            Paddle => (60, 60, 255),
+            Ball => (135, 255, 255),
+        }
+    }
+}
+
+impl Cell for Tile {
+    fn from_char(c: char) -> Option<Self> {
+        use Tile::*;
+        match c {
+            ' ' => Some(Empty),
+            '#' => Some(Wall),
+            '=' => Some(Block),
+            '-' => Some(Paddle),
+            'o' => Some(Ball),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        use Tile::*;
+        match self {
+            Empty => ' ',
+            Wall => '#',
+            Block => '=',
+            Paddle => '-',
+            Ball => 'o',
+        }
+    }
+}
+
+// runs the game without playing it, returning the screen it draws
+fn draw_screen(input: &[i64]) -> Screen {
+    let mut screen = Screen::new();
+    let mut comp = get_computer(input, vec![]);
+    let mut output = vec![];
+
+    loop {
+        match comp.run() {
+            Signal::Halt => break,
+            Signal::ProducedOutput => output.push(comp.get_output().unwrap()),
+            _ => {}
+        }
+
+        if output.len() == 3 {
+            screen.apply(output[0], output[1], output[2]);
+            output.clear();
+        }
+    }
+    screen
+}
+
+fn count_blocks(input: &[i64]) -> usize {
+    draw_screen(input).count(Tile::Block)
+}
+
+// how a game went
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Stats {
+    pub score: i64,
+    pub blocks_broken: usize,
+    pub blocks_left: usize,
+    // how many times the game asked for the joystick
+    pub ticks: usize,
+    // how many of those the joystick was pushed left or right
+    pub moves: usize,
+}
+
+// plays the game until it ends, which is when every block is broken or the
+// ball gets past the paddle
+pub fn play(input: &[i64], renderer: &mut dyn Renderer, strategy: &mut dyn Strategy) -> Stats {
+    record(input, renderer, strategy).0
+}
+
+// plays the game like `play` and records it
+pub fn record(
+    input: &[i64],
+    renderer: &mut dyn Renderer,
+    strategy: &mut dyn Strategy,
+) -> (Stats, Recording) {
+    run_game(input, renderer, &mut |screen| {
+        if screen.ball().is_some() && screen.paddle().is_some() {
+            strategy.joystick(screen)
+        } else {
+            strategy::NEUTRAL
+        }
+    })
+}
+
+// runs the game till it halts, asking `joystick` every time the game wants
+// the joystick, and records everything that happened
+fn run_game(
+    input: &[i64],
+    renderer: &mut dyn Renderer,
+    joystick: &mut dyn FnMut(&Screen) -> i64,
+) -> (Stats, Recording) {
+    let mut stats = Stats::default();
+    let mut recording = Recording::default();
+    let mut screen = Screen::new();
+    let mut comp = get_computer(input, vec![]);
+    let mut output = vec![];
+
+    loop {
+        match comp.run() {
+            Signal::Halt => break,
+            Signal::ProducedOutput => output.push(comp.get_output().unwrap()),
+            Signal::NeedsInput => {
+                let joystick = joystick(&screen);
+                stats.ticks += 1;
+                recording.events.push(Event::Joystick(joystick));
+                if joystick != 0 {
+                    stats.moves += 1;
+                }
+                comp.feed_input(joystick);
+            }
+            _ => {}
+        }
+
+        if output.len() != 3 {
+            continue;
+        }
+
+        let (x, y, z) = (output[0], output[1], output[2]);
+        output.clear();
+
+        let change = screen.apply(x, y, z);
+        match change {
+            Change::Score => recording.events.push(Event::Score(z)),
+            Change::Tile(p, was) => {
+                let tile = screen.get(p).unwrap();
+                if was == Some(Tile::Block) && tile != Tile::Block {
+                    stats.blocks_broken += 1;
+                }
+                recording.events.push(Event::Tile(x, y, tile));
+            }
+        }
+        renderer.draw(&screen, change);
+    }
+
+    stats.score = screen.score();
+    stats.blocks_left = screen.count(Tile::Block);
+    (stats, recording)
+}
+
+// plays a recorded game again on a fresh machine, feeding it the recorded
+// joystick moves in order, failing if anything comes out differently
+pub fn replay(
+    input: &[i64],
+    recording: &Recording,
+    renderer: &mut dyn Renderer,
+) -> Result<Stats, String> {
+    let mut moves = recording.joysticks();
+    let (stats, again) = run_game(input, renderer, &mut |_| {
+        moves.next().unwrap_or(strategy::NEUTRAL)
+    });
+    let diverged = recording
+        .events
+        .iter()
+        .zip(again.events.iter())
+        .position(|(a, b)| a != b)
+        .or_else(|| {
+            if recording.events.len() != again.events.len() {
+                Some(recording.events.len().min(again.events.len()))
+            } else {
+                None
+            }
+        });
+
+    match diverged {
+        Some(i) => Err(format!(
+            "the replay went differently from event {} on",
+            i + 1
+        )),
+        None => Ok(stats),
+    }
+}
+
+// the game with quarters inserted
+pub fn free_play(input: &[i64]) -> Vec<i64> {
+    let mut input = input.to_vec();
+    input[0] = 2;
+    input
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Vec<i64> {
+        parse_program(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> usize {
+        count_blocks(input)
+    }
+
+    fn part2(input: &Vec<i64>) -> i64 {
+        play(&free_play(input), &mut Headless, &mut Tracker).score
+    }
+}
+
+solution::answer_tests!(Day13);
+
+#[cfg(test)]
+mod tests {
+    use super::strategy::{NEUTRAL, RIGHT};
+    use super::{
+        count_blocks, play, record, replay, Event, Headless, Predictive, Recording, Screen,
+        Strategy, Tracker,
+    };
+
+    #[test]
+    fn draws_tiles() {
+        // a paddle at (1, 2), a ball at (6, 5) and a block at (3, 3)
+        let prog = [
+            104, 1, 104, 2, 104, 3, 104, 6, 104, 5, 104, 4, 104, 3, 104, 3, 104, 2, 99,
+        ];
+        assert_eq!(count_blocks(&prog), 1);
+    }
+
+    #[test]
+    fn keeps_score() {
+        // draws a ball, then shows a score of 42 and another of 7
+        let prog = [
+            104, 1, 104, 1, 104, 4, 104, -1, 104, 0, 104, 42, 104, -1, 104, 0, 104, 7, 99,
+        ];
+        assert_eq!(play(&prog, &mut Headless, &mut Tracker).score, 7);
+    }
+
+    #[test]
+    fn strategies_win() {
+        let tracker = play(&GAME, &mut Headless, &mut Tracker);
+        let predictive = play(&GAME, &mut Headless, &mut Predictive::new());
+
+        for stats in [tracker, predictive].iter() {
+            assert_eq!(stats.blocks_left, 0);
+            assert_eq!(stats.blocks_broken, 1);
+            assert_eq!(stats.score, 11);
+            assert_eq!((stats.ticks, stats.moves), (2, 1));
+        }
+
+        // leaving the joystick alone doesn't break the block
+        struct Still;
+        impl Strategy for Still {
+            fn joystick(&mut self, _screen: &Screen) -> i64 {
+                NEUTRAL
+            }
+        }
+        let still = play(&GAME, &mut Headless, &mut Still);
+        assert_eq!((still.blocks_left, still.score, still.moves), (1, 10, 0));
+    }
+
+    // asks for the joystick before anything is drawn, then draws a paddle at
+    // (1, 2), a block at (5, 0) and the ball at (3, 0). moving right breaks
+    // the block, and the score is the last joystick position plus 10.
+    const GAME: [i64; 46] = [
+        3, 100, 104, 1, 104, 2, 104, 3, 104, 5, 104, 0, 104, 2, 104, 3, 104, 0, 104, 4, 3, 100,
+        1008, 100, 1, 102, 1006, 102, 35, 104, 5, 104, 0, 104, 0, 1001, 100, 10, 101, 104, -1, 104,
+        0, 4, 101, 99,
+    ];
+
+    #[test]
+    fn replays() {
+        let (stats, recording) = record(&GAME, &mut Headless, &mut Tracker);
+        assert_eq!(
+            recording.joysticks().collect::<Vec<i64>>(),
+            vec![NEUTRAL, RIGHT]
+        );
+
+        let saved = Recording::parse(&recording.to_string()).unwrap();
+        assert_eq!(replay(&GAME, &saved, &mut Headless), Ok(stats));
+
+        // one move missing changes the game
+        let mut wrong = saved.clone();
+        let first_move = wrong
+            .events
+            .iter()
+            .position(|e| *e == Event::Joystick(RIGHT))
+            .unwrap();
+        wrong.events.remove(first_move);
+        assert!(replay(&GAME, &wrong, &mut Headless).is_err());
+    }
+}
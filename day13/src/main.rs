@@ -1,175 +1,190 @@
-use std::fs;
-
-use intcode::{get_computer, Signal};
-use std::collections::HashMap;
+use day13::{
+    Headless, Predictive, Recording, Renderer, Strategy, Terminal, TerminalKeyboard, Tracker,
+};
+use solution::Solution;
+use std::env;
 use std::error::Error;
-use std::{thread, time};
-
-use sdl2::{pixels::Color, rect::Rect, render::Canvas, video::Window};
-
-fn get_input() -> Result<Vec<i64>, Box<dyn Error>> {
-    let s = fs::read_to_string("input")?;
-    Ok(s.split(',').filter_map(|x| x.parse::<i64>().ok()).collect())
-}
-
-struct Display {
-    canvas: Canvas<Window>,
-    scale: i64,
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "usage: day13 [input] [options]
+
+options:
+    --render <how>         how to show the game in part 2: headless, terminal
+                           or sdl when built with the sdl feature (default
+                           headless)
+    --strategy <who>       who moves the paddle: tracker, predictive or human
+                           with the arrow keys (default tracker)
+    --record <path>        save the game's joystick moves and screen updates
+    --replay <path>        play a saved game again instead of a strategy
+    --frames <dir>         save a PPM image of the screen for every move
+    --gif <path>           save the game as an animated GIF";
+
+// how long the terminal waits every time the ball moves
+const TERMINAL_DELAY: Duration = Duration::from_millis(5);
+
+// how long the game waits between moves when someone is playing
+const HUMAN_DELAY: Duration = Duration::from_millis(100);
+
+// size of a tile in the saved images
+const IMAGE_SCALE: usize = 4;
+
+// hundredths of a second between GIF frames
+const GIF_DELAY: u16 = 2;
+
+struct Options {
+    input: String,
+    render: String,
+    strategy: String,
+    record: Option<String>,
+    replay: Option<String>,
+    frames: Option<String>,
+    gif: Option<String>,
 }
 
-impl Display {
-    fn new(width: u32, height: u32, scale: u32) -> Self {
-        let sdl_context = sdl2::init().unwrap();
-        let video_subsystem = sdl_context.video().unwrap();
-
-        let window = video_subsystem
-            .window("arcade", width * scale, height * scale)
-            .position_centered()
-            .build()
-            .unwrap();
-        let mut canvas = window.into_canvas().build().unwrap();
-
-        canvas.set_draw_color(Color::RGB(0, 0, 0));
-        canvas.clear();
-        canvas.present();
-
-        Display {
-            canvas,
-            scale: scale as i64,
+fn parse_args(args: &[String]) -> Result<Options, Box<dyn Error>> {
+    let mut opts = Options {
+        input: concat!(env!("CARGO_MANIFEST_DIR"), "/input").to_string(),
+        render: "headless".to_string(),
+        strategy: "tracker".to_string(),
+        record: None,
+        replay: None,
+        frames: None,
+        gif: None,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--render" | "--strategy" | "--record" | "--replay" | "--frames" | "--gif" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", arg))?
+                    .clone();
+                match arg.as_str() {
+                    "--render" => opts.render = value,
+                    "--strategy" => opts.strategy = value,
+                    "--record" => opts.record = Some(value),
+                    "--replay" => opts.replay = Some(value),
+                    "--frames" => opts.frames = Some(value),
+                    _ => opts.gif = Some(value),
+                }
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg).into()),
+            _ => opts.input = arg.clone(),
         }
     }
 
-    fn draw_tile(&mut self, x: i64, y: i64, tile: Tile) {
-        self.canvas.set_draw_color(tile.color());
-        self.canvas
-            .fill_rect(Rect::new(
-                (x * self.scale) as i32,
-                (y * self.scale) as i32,
-                self.scale as u32,
-                self.scale as u32,
-            ))
-            .unwrap();
-        self.canvas.present();
-    }
-}
-
-#[derive(Clone, Copy, PartialEq)]
-enum Tile {
-    Empty,
-    Wall,
-    Block,
-    Paddle,
-    Ball,
+    Ok(opts)
 }
 
-impl Tile {
-    fn from_id(id: i64) -> Self {
-        use Tile::*;
-        match id {
-            0 => Empty,
-            1 => Wall,
-            2 => Block,
-            3 => Paddle,
-            4 => Ball,
-            _ => panic!("unexpected output"),
+type Players = (Box<dyn Renderer>, Box<dyn Strategy>);
+
+// the renderer and the strategy, someone playing needs the keyboard of the
+// screen they are looking at
+fn players(opts: &Options) -> Result<Players, Box<dyn Error>> {
+    let human = match opts.strategy.as_str() {
+        "tracker" | "predictive" => false,
+        "human" => true,
+        _ => return Err(format!("unknown strategy {}", opts.strategy).into()),
+    };
+
+    let (renderer, keyboard): Players = match opts.render.as_str() {
+        #[cfg(feature = "sdl")]
+        "sdl" => {
+            let window = day13::SdlWindow::new(38, 21, 10);
+            let keyboard = window.keyboard(HUMAN_DELAY);
+            (Box::new(window), Box::new(keyboard))
         }
-    }
-
-    fn color(&self) -> Color {
-        use Tile::*;
-        match self {
-            Empty => Color::RGB(85, 85, 85),
-            Wall => Color::RGB(255, 255, 255),
-            Block => Color::RGB(255, 61, 61),
-            Paddle => Color::RGB(60, 60, 255),
-            Ball => Color::RGB(135, 255, 255),
+        #[cfg(not(feature = "sdl"))]
+        "sdl" => return Err("built without SDL, rebuild with --features sdl".into()),
+        "terminal" if human => (
+            Box::new(Terminal::new(Duration::from_millis(0))),
+            Box::new(TerminalKeyboard::new(HUMAN_DELAY)),
+        ),
+        "terminal" => (Box::new(Terminal::new(TERMINAL_DELAY)), Box::new(Tracker)),
+        "headless" if human => {
+            return Err("there's no screen to play on, pick one with --render".into())
         }
-    }
+        "headless" => (Box::new(Headless), Box::new(Tracker)),
+        _ => return Err(format!("unknown renderer {}", opts.render).into()),
+    };
+
+    let strategy: Box<dyn Strategy> = match opts.strategy.as_str() {
+        "predictive" => Box::new(Predictive::new()),
+        "human" => keyboard,
+        _ => Box::new(Tracker),
+    };
+    Ok((renderer, strategy))
 }
 
-fn part1(input: &Vec<i64>) -> usize {
-    let mut count = 0;
-    let mut hs: HashMap<(i64, i64), i64> = HashMap::new();
-
-    let mut comp = get_computer(&input, vec![]);
-    let mut output = vec![];
-
-    'main_loop: loop {
-        match comp.run() {
-            Signal::Halt => break 'main_loop,
-            Signal::ProducedOutput => output.push(comp.get_output().unwrap()),
-            _ => {}
-        }
-
-        if output.len() == 3 {
-            let (x, y, z) = (output[0], output[1], output[2]);
-            output.clear();
+// plays part 2 the way the options say and saves what was asked for
+fn play(input: &[i64], opts: &Options) -> Result<(), Box<dyn Error>> {
+    let (mut renderer, mut strategy) = players(opts)?;
+    let game = day13::free_play(input);
 
-            if z == 2 && (!hs.contains_key(&(x, y)) || *hs.get(&(x, y)).unwrap() != 2) {
-                count += 1;
-            }
-            hs.entry((x, y)).and_modify(|e| *e = z);
+    let (stats, recording) = match &opts.replay {
+        Some(path) => {
+            let recording = Recording::parse(&fs::read_to_string(path)?)?;
+            let stats = day13::replay(&game, &recording, renderer.as_mut())?;
+            (stats, recording)
         }
+        None => day13::record(&game, renderer.as_mut(), strategy.as_mut()),
+    };
+    // the terminal renderer puts the cursor back under the game and the
+    // keyboard gives the terminal back when dropped
+    drop(strategy);
+    drop(renderer);
+
+    println!("Part 2: {}", stats.score);
+    let who = if opts.replay.is_some() {
+        "replay"
+    } else {
+        &opts.strategy
+    };
+    println!(
+        "  {} broke {} blocks, {} left, in {} ticks moving {} times",
+        who, stats.blocks_broken, stats.blocks_left, stats.ticks, stats.moves
+    );
+
+    if let Some(path) = &opts.record {
+        fs::write(path, recording.to_string())?;
+    }
+    if opts.frames.is_none() && opts.gif.is_none() {
+        return Ok(());
     }
-    count
-}
-
-fn part2(input: &Vec<i64>) -> i64 {
-    use Tile::*;
-
-    let mut score: i64 = 0;
-    let mut display = Display::new(38, 21, 10);
-    let mut comp = get_computer(&input, vec![]);
-    let mut ball_x = -1;
-    let mut paddle_x = -1;
-    let mut output = vec![];
-
-    loop {
-        match comp.run() {
-            Signal::Halt => break,
-            Signal::ProducedOutput => output.push(comp.get_output().unwrap()),
-            Signal::NeedsInput => {
-                use std::cmp::Ordering::*;
-                let inp = match ball_x.cmp(&paddle_x) {
-                    Less => -1,
-                    Equal => 0,
-                    Greater => 1,
-                };
-                comp.feed_input(inp);
-            }
-            _ => {}
-        }
-
-        if output.len() != 3 {
-            continue;
-        }
-
-        let (x, y, z) = (output[0], output[1], output[2]);
-        output.clear();
-
-        if x == -1 && y == 0 {
-            score = z;
-            continue;
-        }
 
-        let tile = Tile::from_id(z);
-        match tile {
-            Ball => ball_x = x,
-            Paddle => paddle_x = x,
-            _ => {}
+    let frames = recording.frames();
+    if let Some(dir) = &opts.frames {
+        fs::create_dir_all(dir)?;
+        for (i, frame) in frames.iter().enumerate() {
+            let path = Path::new(dir).join(format!("frame-{:05}.ppm", i));
+            day13::write_ppm(&mut BufWriter::new(File::create(path)?), frame, IMAGE_SCALE)?;
         }
-
-        display.draw_tile(x, y, tile);
-        thread::sleep(time::Duration::from_millis(1));
     }
-
-    score
+    if let Some(path) = &opts.gif {
+        let out = BufWriter::new(File::create(path)?);
+        day13::write_gif(out, &frames, IMAGE_SCALE, GIF_DELAY)?;
+    }
+    Ok(())
 }
 
 fn main() {
-    let mut input = get_input().unwrap();
-    println!("Part1: {}", part1(&input));
-
-    input[0] = 2;
-    println!("Part1: {}", part2(&input));
+    let args: Vec<String> = env::args().skip(1).collect();
+    let opts = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}\n\n{}", e, USAGE);
+        process::exit(1);
+    });
+
+    let raw = fs::read_to_string(&opts.input).unwrap();
+    let input = day13::Day13::parse(&raw);
+
+    println!("Part 1: {}", day13::Day13::part1(&input));
+    if let Err(e) = play(&input, &opts) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,110 @@
+use crate::screen::SCORE_AT;
+use crate::{Screen, Tile};
+use std::fmt;
+
+// something that happened during a game, in the order it happened
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Event {
+    Joystick(i64),
+    Tile(i64, i64, Tile),
+    Score(i64),
+}
+
+// everything that went in and out of the arcade during a game
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Recording {
+    pub events: Vec<Event>,
+}
+
+impl Recording {
+    // reads a recording written with `to_string`, one event per line
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut events = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let bad = || format!("line {}: bad event {:?}", i + 1, line);
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let mut numbers = vec![];
+            for word in words.iter().skip(1) {
+                numbers.push(word.parse::<i64>().map_err(|_| bad())?);
+            }
+
+            let event = match (words.first(), &numbers[..]) {
+                (Some(&"joystick"), &[j]) => Event::Joystick(j),
+                (Some(&"tile"), &[x, y, id]) => {
+                    let tile = Tile::try_from_id(id).ok_or_else(bad)?;
+                    Event::Tile(x, y, tile)
+                }
+                (Some(&"score"), &[s]) => Event::Score(s),
+                (None, _) => continue,
+                _ => return Err(bad()),
+            };
+            events.push(event);
+        }
+        Ok(Recording { events })
+    }
+
+    pub fn joysticks(&self) -> impl Iterator<Item = i64> + '_ {
+        self.events.iter().filter_map(|e| match e {
+            Event::Joystick(j) => Some(*j),
+            _ => None,
+        })
+    }
+
+    // the screen every time the game asked for the joystick, and once more
+    // at the end
+    pub fn frames(&self) -> Vec<Screen> {
+        let mut frames = vec![];
+        let mut screen = Screen::new();
+        for event in self.events.iter() {
+            match event {
+                Event::Joystick(_) => frames.push(screen.clone()),
+                Event::Tile(x, y, tile) => {
+                    screen.set((*x, *y), *tile);
+                }
+                Event::Score(s) => {
+                    screen.apply(SCORE_AT.0, SCORE_AT.1, *s);
+                }
+            }
+        }
+        frames.push(screen);
+        frames
+    }
+}
+
+impl fmt::Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for event in self.events.iter() {
+            match event {
+                Event::Joystick(j) => writeln!(f, "joystick {}", j)?,
+                Event::Tile(x, y, tile) => writeln!(f, "tile {} {} {}", x, y, tile.id())?,
+                Event::Score(s) => writeln!(f, "score {}", s)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Event, Recording};
+    use crate::Tile;
+
+    #[test]
+    fn round_trip() {
+        let recording = Recording {
+            events: vec![
+                Event::Tile(1, 2, Tile::Paddle),
+                Event::Tile(3, 3, Tile::Ball),
+                Event::Joystick(-1),
+                Event::Score(12),
+            ],
+        };
+        let text = recording.to_string();
+        assert_eq!(text, "tile 1 2 3\ntile 3 3 4\njoystick -1\nscore 12\n");
+        assert_eq!(Recording::parse(&text), Ok(recording.clone()));
+        assert_eq!(recording.frames().len(), 2);
+
+        assert!(Recording::parse("tile 1 2 9").is_err());
+        assert!(Recording::parse("jump 1").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Change, Screen, Tile};
+use grid::Cell;
+use std::io::{self, Stdout, Write};
+use std::thread;
+use std::time::Duration;
+
+// something that shows the arcade screen as the game draws it. called after
+// every change with the screen as it is now.
+pub trait Renderer {
+    fn draw(&mut self, screen: &Screen, change: Change);
+}
+
+// draws nothing, for tests and machines without a screen
+pub struct Headless;
+
+impl Renderer for Headless {
+    fn draw(&mut self, _screen: &Screen, _change: Change) {}
+}
+
+// draws the game in the terminal with ANSI escapes, the score goes on the
+// first line and the screen below it
+pub struct Terminal {
+    out: Stdout,
+    // how long to wait every time the ball moves
+    delay: Duration,
+    bottom: i64,
+}
+
+impl Terminal {
+    pub fn new(delay: Duration) -> Self {
+        let mut out = io::stdout();
+        // clear the screen and hide the cursor
+        write!(out, "\x1b[2J\x1b[?25l").unwrap();
+        Terminal {
+            out,
+            delay,
+            bottom: 0,
+        }
+    }
+}
+
+impl Renderer for Terminal {
+    fn draw(&mut self, screen: &Screen, change: Change) {
+        let (x, y) = match change {
+            Change::Tile(p, _) => p,
+            Change::Score => {
+                write!(self.out, "\x1b[1;1H\x1b[KScore: {}", screen.score()).unwrap();
+                return;
+            }
+        };
+
+        let tile = screen.get((x, y)).unwrap();
+        write!(self.out, "\x1b[{};{}H{}", y + 2, x + 1, tile.to_char()).unwrap();
+        self.bottom = self.bottom.max(y + 2);
+
+        if tile == Tile::Ball {
+            self.out.flush().unwrap();
+            thread::sleep(self.delay);
+        }
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        // leave the cursor under the game
+        let _ = write!(self.out, "\x1b[{};1H\x1b[?25h", self.bottom + 1);
+        let _ = self.out.flush();
+    }
+}
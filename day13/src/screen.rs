@@ -0,0 +1,126 @@
+use crate::Tile;
+use grid::{Cell, Point, SparseGrid};
+use std::fmt;
+
+// where the game shows the score instead of a tile
+pub const SCORE_AT: Point = (-1, 0);
+
+// what an output of the game changed on the screen
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Change {
+    // the tile at a point, and what was there before
+    Tile(Point, Option<Tile>),
+    Score,
+}
+
+// the arcade screen as drawn so far
+#[derive(Clone, Debug, Default)]
+pub struct Screen {
+    tiles: SparseGrid<Tile>,
+    score: i64,
+    ball: Option<Point>,
+    paddle: Option<Point>,
+}
+
+impl Screen {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // applies one (x, y, value) output of the game, the value is a tile id or
+    // the score
+    pub fn apply(&mut self, x: i64, y: i64, value: i64) -> Change {
+        if (x, y) == SCORE_AT {
+            self.score = value;
+            return Change::Score;
+        }
+        self.set((x, y), Tile::from_id(value))
+    }
+
+    pub fn set(&mut self, p: Point, tile: Tile) -> Change {
+        match tile {
+            Tile::Ball => self.ball = Some(p),
+            Tile::Paddle => self.paddle = Some(p),
+            _ => {}
+        }
+        // drawing over the ball or the paddle takes it away
+        if self.ball == Some(p) && tile != Tile::Ball {
+            self.ball = None;
+        }
+        if self.paddle == Some(p) && tile != Tile::Paddle {
+            self.paddle = None;
+        }
+
+        Change::Tile(p, self.tiles.insert(p, tile))
+    }
+
+    pub fn get(&self, p: Point) -> Option<Tile> {
+        self.tiles.get(p).copied()
+    }
+
+    pub fn score(&self) -> i64 {
+        self.score
+    }
+
+    pub fn ball(&self) -> Option<Point> {
+        self.ball
+    }
+
+    pub fn paddle(&self) -> Option<Point> {
+        self.paddle
+    }
+
+    // how many of `tile` are on the screen
+    pub fn count(&self, tile: Tile) -> usize {
+        self.tiles.iter().filter(|(_, t)| **t == tile).count()
+    }
+
+    // top left and bottom right corners of what's drawn, without the score
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.tiles.bounds()
+    }
+}
+
+// the score above the tiles
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Score: {}", self.score)?;
+        write!(f, "{}", self.tiles.render(|t| t.map_or(' ', Cell::to_char)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, Screen};
+    use crate::Tile;
+
+    #[test]
+    fn applies_outputs() {
+        let mut screen = Screen::new();
+        assert_eq!(screen.apply(0, 0, 1), Change::Tile((0, 0), None));
+        screen.apply(1, 1, 2);
+        screen.apply(2, 1, 2);
+        screen.apply(1, 2, 3);
+        screen.apply(2, 0, 4);
+        assert_eq!(screen.apply(-1, 0, 30), Change::Score);
+
+        assert_eq!(screen.count(Tile::Block), 2);
+        assert_eq!(screen.ball(), Some((2, 0)));
+        assert_eq!(screen.paddle(), Some((1, 2)));
+        assert_eq!(screen.bounds(), Some(((0, 0), (2, 2))));
+        assert_eq!(screen.to_string(), "Score: 30\n# o\n ==\n - \n");
+
+        // the ball breaks a block and leaves its old place
+        assert_eq!(
+            screen.apply(1, 1, 0),
+            Change::Tile((1, 1), Some(Tile::Block))
+        );
+        screen.apply(2, 0, 0);
+        screen.apply(1, 0, 4);
+        assert_eq!(screen.count(Tile::Block), 1);
+        assert_eq!(screen.ball(), Some((1, 0)));
+
+        screen.apply(1, 2, 0);
+        assert_eq!(screen.paddle(), None);
+    }
+}
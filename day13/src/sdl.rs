@@ -0,0 +1,91 @@
+use crate::render::Renderer;
+use crate::strategy::{Strategy, LEFT, NEUTRAL, RIGHT};
+use crate::{Change, Screen};
+use std::{thread, time};
+
+use sdl2::keyboard::Scancode;
+use sdl2::{pixels::Color, rect::Rect, render::Canvas, video::Window, EventPump, Sdl};
+
+// draws the game in an SDL window
+pub struct SdlWindow {
+    sdl: Sdl,
+    canvas: Canvas<Window>,
+    scale: i64,
+}
+
+impl SdlWindow {
+    pub fn new(width: u32, height: u32, scale: u32) -> Self {
+        let sdl_context = sdl2::init().unwrap();
+        let video_subsystem = sdl_context.video().unwrap();
+
+        let window = video_subsystem
+            .window("arcade", width * scale, height * scale)
+            .position_centered()
+            .build()
+            .unwrap();
+        let mut canvas = window.into_canvas().build().unwrap();
+
+        canvas.set_draw_color(Color::RGB(0, 0, 0));
+        canvas.clear();
+        canvas.present();
+
+        SdlWindow {
+            sdl: sdl_context,
+            canvas,
+            scale: scale as i64,
+        }
+    }
+
+    // the arrow keys pressed while the window has focus. the game waits
+    // `delay` between moves so it can be played.
+    pub fn keyboard(&self, delay: time::Duration) -> SdlKeyboard {
+        SdlKeyboard {
+            events: self.sdl.event_pump().unwrap(),
+            delay,
+        }
+    }
+}
+
+impl Renderer for SdlWindow {
+    fn draw(&mut self, screen: &Screen, change: Change) {
+        let (x, y) = match change {
+            Change::Tile(p, _) => p,
+            Change::Score => return,
+        };
+
+        let (r, g, b) = screen.get((x, y)).unwrap().color();
+        self.canvas.set_draw_color(Color::RGB(r, g, b));
+        self.canvas
+            .fill_rect(Rect::new(
+                (x * self.scale) as i32,
+                (y * self.scale) as i32,
+                self.scale as u32,
+                self.scale as u32,
+            ))
+            .unwrap();
+        self.canvas.present();
+        thread::sleep(time::Duration::from_millis(1));
+    }
+}
+
+// someone playing with the arrow keys in the SDL window
+pub struct SdlKeyboard {
+    events: EventPump,
+    delay: time::Duration,
+}
+
+impl Strategy for SdlKeyboard {
+    fn joystick(&mut self, _screen: &Screen) -> i64 {
+        thread::sleep(self.delay);
+
+        self.events.pump_events();
+        let keys = self.events.keyboard_state();
+        if keys.is_scancode_pressed(Scancode::Left) {
+            LEFT
+        } else if keys.is_scancode_pressed(Scancode::Right) {
+            RIGHT
+        } else {
+            NEUTRAL
+        }
+    }
+}
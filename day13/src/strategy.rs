@@ -0,0 +1,213 @@
+use crate::{Screen, Tile};
+use grid::Point;
+use std::io::{self, Read};
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+// the joystick positions
+pub const LEFT: i64 = -1;
+pub const NEUTRAL: i64 = 0;
+pub const RIGHT: i64 = 1;
+
+// moves the paddle. asked every time the game advances once the ball and the
+// paddle are on the screen
+pub trait Strategy {
+    fn joystick(&mut self, screen: &Screen) -> i64;
+}
+
+fn ball_and_paddle(screen: &Screen) -> (Point, Point) {
+    (screen.ball().unwrap(), screen.paddle().unwrap())
+}
+
+fn towards(from: i64, to: i64) -> i64 {
+    (to - from).signum()
+}
+
+// keeps the paddle under the ball
+pub struct Tracker;
+
+impl Strategy for Tracker {
+    fn joystick(&mut self, screen: &Screen) -> i64 {
+        let (ball, paddle) = ball_and_paddle(screen);
+        towards(paddle.0, ball.0)
+    }
+}
+
+// works out where the ball comes down from the way it's moving and waits for
+// it there. when a block is in the way it can't tell how the ball bounces off
+// it, so it follows the ball until the way is clear again.
+#[derive(Default)]
+pub struct Predictive {
+    last_ball: Option<Point>,
+}
+
+// gives up on predicting after this many steps of the ball
+const MAX_STEPS: usize = 1000;
+
+impl Predictive {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // where the ball is when it gets just above the paddle, bouncing off the
+    // walls on the way. None if it would touch a block first.
+    fn landing_x(screen: &Screen, mut ball: Point, mut v: Point, paddle_y: i64) -> Option<i64> {
+        for _ in 0..MAX_STEPS {
+            if ball.1 >= paddle_y - 1 && v.1 > 0 {
+                return Some(ball.0);
+            }
+
+            let (mut dx, mut dy) = v;
+            let side = (ball.0 + dx, ball.1);
+            let above_below = (ball.0, ball.1 + dy);
+            let corner = (ball.0 + dx, ball.1 + dy);
+            for p in [side, above_below, corner].iter() {
+                if screen.get(*p) == Some(Tile::Block) {
+                    return None;
+                }
+            }
+
+            let wall = |p| screen.get(p) == Some(Tile::Wall);
+            if wall(side) {
+                dx = -dx;
+            }
+            if wall(above_below) {
+                dy = -dy;
+            }
+            if (dx, dy) == v && wall(corner) {
+                dx = -dx;
+                dy = -dy;
+            }
+
+            v = (dx, dy);
+            ball = (ball.0 + dx, ball.1 + dy);
+        }
+        None
+    }
+}
+
+impl Strategy for Predictive {
+    fn joystick(&mut self, screen: &Screen) -> i64 {
+        let (ball, paddle) = ball_and_paddle(screen);
+        let landing = match self.last_ball {
+            Some(last) if last != ball => {
+                let v = (ball.0 - last.0, ball.1 - last.1);
+                Self::landing_x(screen, ball, v, paddle.1)
+            }
+            _ => None,
+        };
+        self.last_ball = Some(ball);
+        towards(paddle.0, landing.unwrap_or(ball.0))
+    }
+}
+
+// someone playing with the arrow keys in the terminal. the game waits
+// `delay` between moves so it can be played.
+pub struct TerminalKeyboard {
+    delay: Duration,
+    // only set up once the first move is asked for, so a keyboard nobody
+    // plays on leaves the terminal alone
+    raw: Option<RawTerminal>,
+}
+
+// the terminal reading keys as they are pressed, put back the way it was
+// when dropped, which includes unwinding from a panic
+struct RawTerminal {
+    saved: Option<String>,
+}
+
+fn stty(args: &[&str]) -> Option<String> {
+    let out = Command::new("stty").args(args).output().ok()?;
+    if out.status.success() {
+        Some(String::from_utf8_lossy(&out.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+impl RawTerminal {
+    fn enter() -> Self {
+        // read keys as they are pressed without waiting for them
+        let saved = stty(&["-g"]);
+        stty(&["-icanon", "-echo", "min", "0", "time", "0"]);
+        RawTerminal { saved }
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.saved {
+            stty(&[saved]);
+        }
+    }
+}
+
+impl TerminalKeyboard {
+    pub fn new(delay: Duration) -> Self {
+        TerminalKeyboard { delay, raw: None }
+    }
+}
+
+impl Strategy for TerminalKeyboard {
+    fn joystick(&mut self, _screen: &Screen) -> i64 {
+        self.raw.get_or_insert_with(RawTerminal::enter);
+        thread::sleep(self.delay);
+
+        let mut keys = [0; 64];
+        let n = io::stdin().read(&mut keys).unwrap_or(0);
+        // the last arrow key pressed since the last move wins
+        let mut joystick = NEUTRAL;
+        for key in keys[..n].windows(3) {
+            match key {
+                b"\x1b[D" => joystick = LEFT,
+                b"\x1b[C" => joystick = RIGHT,
+                _ => {}
+            }
+        }
+        joystick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Predictive, Strategy, LEFT, NEUTRAL, RIGHT};
+    use crate::{Screen, Tile};
+    use grid::Point;
+
+    fn moved(screen: &mut Screen, ball: Point, paddle: Point) -> &Screen {
+        screen.set(ball, Tile::Ball);
+        screen.set(paddle, Tile::Paddle);
+        screen
+    }
+
+    #[test]
+    fn predicts_bounces() {
+        // a box with walls at x = 0 and x = 6, the paddle is on row 6
+        let mut screen = Screen::new();
+        for y in 0..7 {
+            screen.set((0, y), Tile::Wall);
+            screen.set((6, y), Tile::Wall);
+        }
+
+        // falling to the right from (4, 1) it bounces off the wall at x = 6
+        // and gets to row 5 at x = 2
+        assert_eq!(Predictive::landing_x(&screen, (4, 1), (1, 1), 6), Some(2));
+
+        let mut strategy = Predictive::new();
+        // the first time it can't tell where the ball is going
+        assert_eq!(strategy.joystick(moved(&mut screen, (3, 0), (2, 6))), RIGHT);
+        assert_eq!(
+            strategy.joystick(moved(&mut screen, (4, 1), (2, 6))),
+            NEUTRAL
+        );
+        assert_eq!(strategy.joystick(moved(&mut screen, (5, 2), (3, 6))), LEFT);
+
+        // with a block in the way it follows the ball
+        screen.set((4, 4), Tile::Block);
+        assert_eq!(Predictive::landing_x(&screen, (4, 1), (1, 1), 6), None);
+        let mut strategy = Predictive::new();
+        strategy.joystick(moved(&mut screen, (3, 0), (3, 6)));
+        assert_eq!(strategy.joystick(moved(&mut screen, (4, 1), (3, 6))), RIGHT);
+    }
+}
@@ -0,0 +1,226 @@
+use solution::Solution;
+use std::collections::HashMap;
+
+struct ReactionDependency {
+    name: String,
+    count: u64,
+}
+
+pub struct Reaction {
+    produced_quantity: u64,
+    dependencies: Vec<ReactionDependency>,
+}
+
+fn get_input(input: &str) -> HashMap<String, Reaction> {
+    let mut mappings = HashMap::<String, Reaction>::new();
+
+    let _split = |item: &str| {
+        let mut rsplit = item.split(" ");
+        let produced_quantity = rsplit.next().unwrap().parse::<u64>().unwrap();
+        let product_name = rsplit.next().unwrap().to_owned();
+        (product_name, produced_quantity)
+    };
+
+    input.trim().split("\n").for_each(|line| {
+        // get reaction
+        let mut split = line.split(" => ");
+        let lhs = split.next().unwrap();
+        let rhs = split.next().unwrap();
+
+        let (product_name, produced_quantity) = _split(rhs);
+        let dependencies: Vec<ReactionDependency> = lhs
+            .split(", ")
+            .map(_split)
+            .map(|(name, count)| ReactionDependency { name, count })
+            .collect();
+
+        mappings.insert(
+            product_name,
+            Reaction {
+                produced_quantity,
+                dependencies,
+            },
+        );
+    });
+
+    mappings
+}
+
+fn part1_recursive_helper(
+    item: &str,
+    quantity: u64,
+    mappings: &HashMap<String, Reaction>,
+    surplus_mapping: &mut HashMap<String, u64>,
+    ore_count: &mut u64,
+) {
+    let mut quantity = quantity;
+
+    if item == "ORE" {
+        *ore_count += quantity;
+        return; // terminate recursion
+    }
+
+    // try fulfilling by surplus already produced
+    let surplus = surplus_mapping.entry(item.to_string()).or_default();
+    if *surplus >= quantity {
+        *surplus -= quantity;
+        return;
+    } else {
+        // we can only fulfil the requirement partially
+        // subtract it from the requirement
+        quantity -= *surplus;
+        *surplus = 0;
+    }
+
+    let reaction = mappings.get(item).unwrap();
+
+    // number of reactions we would have to make to fulfil the requirements
+    let num_reaction: u64 = ((quantity as f64) / (reaction.produced_quantity as f64)).ceil() as u64;
+
+    // this is the surplus we would be producing
+    let surplus_produced = reaction.produced_quantity * num_reaction - quantity;
+
+    // update the dictionary
+    *surplus_mapping.entry(item.to_string()).or_default() += surplus_produced;
+
+    // lets recurse for the dependecies of the current chemical
+    for dependency in reaction.dependencies.iter() {
+        part1_recursive_helper(
+            dependency.name.as_str(),
+            dependency.count * num_reaction,
+            mappings,
+            surplus_mapping,
+            ore_count,
+        );
+    }
+}
+
+fn ores_needed(mappings: &HashMap<String, Reaction>, c: u64) -> u64 {
+    let mut surplus = HashMap::new();
+    let mut ore_count = 0;
+    part1_recursive_helper("FUEL", c, mappings, &mut surplus, &mut ore_count);
+
+    ore_count
+}
+
+fn max_fuel(mappings: &HashMap<String, Reaction>, min_ores_per_fuel: u64) -> u64 {
+    let max_ores = 1000000000000;
+    if min_ores_per_fuel > max_ores {
+        return 0;
+    }
+    // fuel that takes no ore at all never runs out
+    if min_ores_per_fuel == 0 {
+        return u64::MAX;
+    }
+
+    // making fuel in bulk wastes less, so at least this much can be made,
+    // then keep doubling till the ore runs out to find an upper bound
+    let mut start = max_ores / min_ores_per_fuel;
+    let mut end = start.max(1) * 2;
+    while ores_needed(mappings, end) <= max_ores {
+        end *= 2;
+    }
+
+    while start <= end {
+        let mid = start + ((end - start) / 2);
+        let ore_count = ores_needed(mappings, mid);
+        if ore_count == max_ores {
+            return mid;
+        } else if ore_count > max_ores {
+            end = mid.saturating_sub(1);
+        } else if ore_count < max_ores {
+            start = mid + 1;
+        }
+    }
+
+    end // we wnat next next largest count
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = HashMap<String, Reaction>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        get_input(input)
+    }
+
+    fn part1(mappings: &Self::Input) -> u64 {
+        ores_needed(mappings, 1)
+    }
+
+    fn part2(mappings: &Self::Input) -> u64 {
+        let p1 = ores_needed(mappings, 1);
+        max_fuel(mappings, p1)
+    }
+}
+
+solution::answer_tests!(Day14);
+
+#[cfg(test)]
+mod tests {
+    use super::{get_input, max_fuel, ores_needed};
+
+    const SMALL: &str = "10 ORE => 10 A
+1 ORE => 1 B
+7 A, 1 B => 1 C
+7 A, 1 C => 1 D
+7 A, 1 D => 1 E
+7 A, 1 E => 1 FUEL";
+
+    const MEDIUM: &str = "157 ORE => 5 NZVS
+165 ORE => 6 DCFZ
+44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL
+12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ
+179 ORE => 7 PSHF
+177 ORE => 5 HKGWZ
+7 DCFZ, 7 PSHF => 2 XJWVT
+165 ORE => 2 GPVTF
+3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT";
+
+    const LARGE: &str = "171 ORE => 8 CNZTR
+7 ZLQW, 3 BMBT, 9 XCVML, 26 XMNCP, 1 WPTQ, 2 MZWV, 1 RJRHP => 4 PLWSL
+114 ORE => 4 BHXH
+14 VRPVC => 6 BMBT
+6 BHXH, 18 KTJDG, 12 WPTQ, 7 PLWSL, 31 FHTLT, 37 ZDVW => 1 FUEL
+6 WPTQ, 2 BMBT, 8 ZLQW, 18 KTJDG, 1 XMNCP, 6 MZWV, 1 RJRHP => 6 FHTLT
+15 XDBXC, 2 LTCX, 1 VRPVC => 6 ZLQW
+13 WPTQ, 10 LTCX, 3 RJRHP, 14 XMNCP, 2 MZWV, 1 ZLQW => 1 ZDVW
+5 BMBT => 4 WPTQ
+189 ORE => 9 KTJDG
+1 MZWV, 17 XDBXC, 3 XCVML => 2 XMNCP
+12 VRPVC, 27 CNZTR => 2 XDBXC
+15 KTJDG, 12 BHXH => 5 XCVML
+3 BHXH, 2 VRPVC => 7 MZWV
+121 ORE => 7 VRPVC
+7 XCVML => 6 RJRHP
+5 BHXH, 4 VRPVC => 5 LTCX";
+
+    #[test]
+    fn ore_for_one_fuel() {
+        assert_eq!(ores_needed(&get_input(SMALL), 1), 31);
+        assert_eq!(ores_needed(&get_input(MEDIUM), 1), 13312);
+        assert_eq!(ores_needed(&get_input(LARGE), 1), 2210736);
+    }
+
+    #[test]
+    fn fuel_for_a_trillion_ore() {
+        let mappings = get_input(MEDIUM);
+        assert_eq!(max_fuel(&mappings, 13312), 82892753);
+
+        let mappings = get_input(LARGE);
+        assert_eq!(max_fuel(&mappings, 2210736), 460664);
+
+        // not even one fuel
+        let mappings = get_input("2000000000000 ORE => 1 FUEL");
+        assert_eq!(max_fuel(&mappings, 2000000000000), 0);
+        let mappings = get_input("1000000000000 ORE => 1 FUEL");
+        assert_eq!(max_fuel(&mappings, 1000000000000), 1);
+
+        let mappings = get_input("0 ORE => 1 FUEL");
+        assert_eq!(ores_needed(&mappings, 1), 0);
+        assert_eq!(max_fuel(&mappings, 0), u64::MAX);
+    }
+}
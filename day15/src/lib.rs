@@ -0,0 +1,232 @@
+use std::collections::{HashSet, VecDeque};
+
+use grid::{Axes, Cell, Direction, Point, SparseGrid};
+use intcode::{get_computer, parse_program, IntCodeComputer, Signal};
+use solution::Solution;
+
+// what the droid found, with y growing to the north (see Axes::YUp). the
+// oxygen fill doesn't care which way up a map is, so maps parsed from text
+// with y growing downwards work as well.
+type AreaMap = SparseGrid<Tile>;
+
+struct Droid {
+    comp: IntCodeComputer,
+}
+
+impl Droid {
+    fn new(prog: Vec<i64>) -> Self {
+        Droid {
+            comp: get_computer(&prog, vec![]),
+        }
+    }
+
+    fn move_dir(&mut self, dir: Direction) -> i64 {
+        self.comp.feed_input(dir.droid_command());
+        self.comp.run_till_signal(Signal::ProducedOutput);
+        self.comp.get_output().unwrap()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tile {
+    Oxygen(i64),
+    Wall,
+    Floor(i64),
+}
+
+impl Tile {
+    fn from_output(out: i64, path_length: i64) -> Tile {
+        match out {
+            0 => Tile::Wall,
+            1 => Tile::Floor(path_length),
+            2 => Tile::Oxygen(path_length),
+            _ => panic!("should not happen"),
+        }
+    }
+
+    fn value(&self) -> Option<i64> {
+        match self {
+            Tile::Oxygen(v) => Some(*v),
+            Tile::Floor(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+// drawn without the path lengths
+impl Cell for Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'O' => Some(Tile::Oxygen(0)),
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Floor(0)),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Oxygen(_) => 'O',
+            Tile::Wall => '#',
+            Tile::Floor(_) => '.',
+        }
+    }
+}
+
+fn rec_helper(
+    droid: &mut Droid,
+    tile_info: &mut AreaMap,
+    cur_path: i64,
+    smallest_oxy: &mut i64,
+    oxy_location: &mut Point,
+    cur_position: Point,
+) {
+    use Direction::*;
+    use Tile::*;
+
+    for dir in [Up, Down, Right, Left].iter() {
+        let new_pos = dir.step(cur_position, Axes::YUp);
+        let cur_path_len = cur_path + 1;
+
+        let out = droid.move_dir(*dir);
+        let tile = Tile::from_output(out, cur_path_len);
+
+        match tile {
+            Floor(dist) => {
+                let mut should_recurse = true;
+                match tile_info.get_mut(new_pos) {
+                    Some(e) => {
+                        if new_pos != (0, 0) && dist < e.value().unwrap() {
+                            *e = tile;
+                        } else {
+                            should_recurse = false;
+                        }
+                    }
+                    None => {
+                        tile_info.insert(new_pos, tile);
+                    }
+                }
+
+                if should_recurse {
+                    rec_helper(droid, tile_info, dist, smallest_oxy, oxy_location, new_pos);
+                }
+                let _ = droid.move_dir(dir.opposite());
+            }
+            Oxygen(dist) => {
+                if dist < *smallest_oxy {
+                    *smallest_oxy = dist;
+                    *oxy_location = new_pos;
+                    tile_info.insert(new_pos, Tile::from_output(out, *smallest_oxy));
+                }
+                let _ = droid.move_dir(dir.opposite());
+            }
+            _ => {}
+        }
+    }
+}
+
+fn explore(prog: Vec<i64>) -> (i64, AreaMap, Point) {
+    let mut droid = Droid::new(prog);
+    let mut tile_info = AreaMap::new();
+    let mut smallest_path = i64::MAX;
+    let mut oxy_location = (-1, -1);
+
+    tile_info.insert((0, 0), Tile::from_output(0, 0));
+
+    rec_helper(
+        &mut droid,
+        &mut tile_info,
+        0,
+        &mut smallest_path,
+        &mut oxy_location,
+        (0, 0),
+    );
+
+    (smallest_path, tile_info, oxy_location)
+}
+
+fn fill_time(tile_info: &AreaMap, oxy_location: Point) -> i64 {
+    use Tile::*;
+
+    enum Item {
+        Pos(Point),
+        Sentinel,
+    }
+
+    let mut time = -1;
+    let mut has_oxy = HashSet::new();
+    has_oxy.insert(oxy_location);
+
+    let mut queue = VecDeque::new();
+    queue.push_back(Item::Pos(oxy_location));
+
+    while !queue.is_empty() {
+        queue.push_back(Item::Sentinel);
+        time += 1;
+        'inner: loop {
+            match queue.pop_front().unwrap() {
+                Item::Sentinel => {
+                    break 'inner;
+                }
+                Item::Pos(pos) => {
+                    for (new_pos, tile) in tile_info.neighbours(pos) {
+                        if let (Floor(_), false) = (tile, has_oxy.contains(&new_pos)) {
+                            has_oxy.insert(new_pos);
+                            queue.push_back(Item::Pos(new_pos));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    time
+}
+
+pub struct Day15;
+
+// what exploring found, both parts work on it so the droid explores the
+// area only once
+pub struct Area {
+    smallest_path: i64,
+    map: AreaMap,
+    oxy_location: Point,
+}
+
+impl Solution for Day15 {
+    type Input = Area;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Area {
+        let (smallest_path, map, oxy_location) = explore(parse_program(input));
+        Area {
+            smallest_path,
+            map,
+            oxy_location,
+        }
+    }
+
+    fn part1(area: &Area) -> i64 {
+        area.smallest_path
+    }
+
+    fn part2(area: &Area) -> i64 {
+        fill_time(&area.map, area.oxy_location)
+    }
+}
+
+solution::answer_tests!(Day15);
+
+#[cfg(test)]
+mod tests {
+    use super::{fill_time, AreaMap};
+
+    #[test]
+    fn oxygen_spreads() {
+        let map = AreaMap::parse(" ##   \n#..## \n#.#..#\n#.O.# \n ###  ", ' ');
+        assert_eq!(map.to_string(), " ##   \n#..## \n#.#..#\n#.O.# \n ###  \n");
+        // parsed with y growing downwards, the oxygen is on the fourth row
+        assert_eq!(fill_time(&map, (2, 3)), 4);
+    }
+}
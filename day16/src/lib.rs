@@ -0,0 +1,122 @@
+use solution::Solution;
+
+fn get_input(input: &[i64], repeat: usize) -> Vec<i64> {
+    input
+        .iter()
+        .cycle()
+        .take(input.len() * repeat)
+        .cloned()
+        .collect::<Vec<i64>>()
+}
+
+fn iterator_for(times: usize, len: usize) -> impl Iterator<Item = i64> {
+    // the whole pattern repeats, only its very first value is skipped
+    std::iter::repeat_n(0, times)
+        .chain(std::iter::repeat_n(1, times))
+        .chain(std::iter::repeat_n(0, times))
+        .chain(std::iter::repeat_n(-1, times))
+        .cycle()
+        .skip(1)
+        .take(len)
+}
+
+fn fft(input: &[i64], phases: usize) -> String {
+    let mut input = input.to_vec();
+    let n = input.len();
+
+    for _ in 0..phases {
+        let mut ans = vec![];
+
+        for i in 1..=n {
+            let mut accum = 0;
+            for (a, b) in input.iter().zip(iterator_for(i, n)) {
+                accum += a * b;
+            }
+            ans.push(accum.abs() % 10);
+        }
+        input = ans;
+    }
+
+    input
+        .iter()
+        .take(8)
+        .map(|x| x.to_string())
+        .collect::<String>()
+}
+
+fn real_signal(input: &[i64]) -> String {
+    let input = get_input(input, 10000);
+    let index = input[0..7].iter().fold(0, |acc, x| acc * 10 + x);
+    let mut input = input[(index as usize)..(input.len())].to_owned();
+
+    for _ in 0..100 {
+        let mut running_sum = 0;
+        for i in (0..(input.len())).rev() {
+            running_sum += input[i];
+            input[i] = running_sum.abs() % 10;
+        }
+    }
+
+    input
+        .iter()
+        .take(8)
+        .map(|x| x.to_string())
+        .collect::<String>()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<i64>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Vec<i64> {
+        input
+            .trim()
+            .chars()
+            .map(|x| x.to_digit(10).unwrap() as i64)
+            .collect()
+    }
+
+    fn part1(input: &Vec<i64>) -> String {
+        fft(input, 100)
+    }
+
+    fn part2(input: &Vec<i64>) -> String {
+        real_signal(input)
+    }
+}
+
+solution::answer_tests!(Day16);
+
+#[cfg(test)]
+mod tests {
+    use super::{fft, real_signal, Day16};
+    use solution::Solution;
+
+    #[test]
+    fn phases() {
+        assert_eq!(fft(&Day16::parse("12345678"), 4), "01029498");
+        assert_eq!(
+            fft(&Day16::parse("80871224585914546619083218645595"), 100),
+            "24176176"
+        );
+        assert_eq!(
+            fft(&Day16::parse("19617804207202209144916044189917"), 100),
+            "73745418"
+        );
+        assert_eq!(
+            fft(&Day16::parse("69317163492948606335995924319873"), 100),
+            "52432133"
+        );
+    }
+
+    #[test]
+    fn message_offset() {
+        let input = Day16::parse("03036732577212944063491565474664");
+        assert_eq!(real_signal(&input), "84462026");
+        let input = Day16::parse("02935109699940807407585447034323");
+        assert_eq!(real_signal(&input), "78725270");
+    }
+}
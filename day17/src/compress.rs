@@ -0,0 +1,136 @@
+// the robot's memory limits for the main routine and the movement functions
+const MAX_FUNCTIONS: usize = 3;
+const MAX_CHARS: usize = 20;
+
+// a main routine calling movement functions A, B and C, which together walk
+// a path of commands like "L" or "12"
+#[derive(Clone, PartialEq, Debug)]
+pub struct Routine {
+    pub main: Vec<usize>,
+    pub functions: Vec<Vec<String>>,
+}
+
+impl Routine {
+    pub fn main_text(&self) -> String {
+        self.main
+            .iter()
+            .map(|&f| ((b'A' + f as u8) as char).to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    // the path the routine walks when run
+    pub fn expand(&self) -> Vec<String> {
+        self.main
+            .iter()
+            .flat_map(|&f| self.functions[f].iter().cloned())
+            .collect()
+    }
+
+    // what the ASCII program is fed, a line for each of the main routine and
+    // the three functions, then the answer to the video feed prompt
+    pub fn to_input(&self, video: bool) -> String {
+        let mut lines = vec![self.main_text()];
+        for f in 0..MAX_FUNCTIONS {
+            lines.push(self.functions.get(f).map_or(String::new(), |f| f.join(",")));
+        }
+        lines.push(if video { "y" } else { "n" }.to_string());
+
+        lines.iter().map(|l| format!("{}\n", l)).collect()
+    }
+}
+
+fn fits(commands: &[String]) -> bool {
+    commands.iter().map(|c| c.len() + 1).sum::<usize>() - 1 <= MAX_CHARS
+}
+
+// every routine that walks `path` within the memory limits. functions are
+// named in the order they are first called, so no routine is listed twice.
+pub fn compress(path: &[String]) -> Vec<Routine> {
+    let mut found = vec![];
+    let mut routine = Routine {
+        main: vec![],
+        functions: vec![],
+    };
+    search(path, &mut routine, &mut found);
+    found
+}
+
+fn search(rest: &[String], routine: &mut Routine, found: &mut Vec<Routine>) {
+    if rest.is_empty() {
+        found.push(routine.clone());
+        return;
+    }
+    // a call is a letter and a comma
+    if routine.main.len() * 2 + 1 > MAX_CHARS {
+        return;
+    }
+
+    for f in 0..routine.functions.len() {
+        let len = routine.functions[f].len();
+        if rest.starts_with(&routine.functions[f]) {
+            routine.main.push(f);
+            search(&rest[len..], routine, found);
+            routine.main.pop();
+        }
+    }
+
+    if routine.functions.len() < MAX_FUNCTIONS {
+        for len in 1..=rest.len() {
+            let function = &rest[..len];
+            if !fits(function) {
+                break;
+            }
+            if routine.functions.iter().any(|f| f[..] == *function) {
+                continue;
+            }
+
+            routine.functions.push(function.to_vec());
+            routine.main.push(routine.functions.len() - 1);
+            search(&rest[len..], routine, found);
+            routine.main.pop();
+            routine.functions.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compress, fits, Routine};
+
+    fn commands(s: &str) -> Vec<String> {
+        s.split(',').map(String::from).collect()
+    }
+
+    #[test]
+    fn example() {
+        let path = commands("R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2");
+        let routines = compress(&path);
+
+        let expected = Routine {
+            main: vec![0, 1, 2, 1, 0, 2],
+            functions: vec![
+                commands("R,8,R,8"),
+                commands("R,4,R,4,R,8"),
+                commands("L,6,L,2"),
+            ],
+        };
+        assert!(routines.contains(&expected));
+        assert_eq!(expected.main_text(), "A,B,C,B,A,C");
+
+        for routine in routines.iter() {
+            assert_eq!(routine.expand(), path);
+            assert!(routine.main_text().len() <= 20);
+            assert!(routine.functions.iter().all(|f| fits(f)));
+        }
+    }
+
+    #[test]
+    fn too_long() {
+        // every distance is different, so nothing repeats
+        let path: Vec<String> = (1..30)
+            .flat_map(|n| vec!["L".to_string(), n.to_string()])
+            .collect();
+        assert!(compress(&path).is_empty());
+    }
+}
@@ -0,0 +1,247 @@
+mod compress;
+mod simulate;
+mod video;
+
+pub use compress::{compress, Routine};
+pub use simulate::{simulate, Walk};
+pub use video::watch;
+
+use grid::{Axes, Cell, Direction, Grid, Point, Turn};
+use intcode::{get_computer, parse_program, IntCodeComputer, Signal};
+use solution::Solution;
+use std::fmt;
+
+pub struct Maze {
+    grid: Grid<Item>,
+}
+
+impl fmt::Display for Maze {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Maze {
+    // false outside the maze
+    fn is_scaffold(&self, p: Point) -> bool {
+        self.grid.get(p) == Some(&Item::Scaffold)
+    }
+
+    fn is_intersection(&self, p: Point) -> bool {
+        self.is_scaffold(p)
+            && self
+                .grid
+                .neighbours(p)
+                .filter(|(_, item)| **item == Item::Scaffold)
+                .count()
+                == 4
+    }
+
+    fn robot(&self) -> (Point, Direction) {
+        self.grid
+            .iter()
+            .find_map(|(p, item)| item.get_dir().map(|dir| (p, dir)))
+            .expect("no robot in the maze")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Item {
+    Scaffold,
+    Empty,
+    Robot(Direction),
+    // the robot fell off the scaffold
+    Tumbling,
+}
+
+impl Cell for Item {
+    fn from_char(c: char) -> Option<Item> {
+        match c {
+            '#' => Some(Item::Scaffold),
+            '.' => Some(Item::Empty),
+            'X' => Some(Item::Tumbling),
+            _ => Direction::from_char(c).map(Item::Robot),
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Item::Scaffold => '#',
+            Item::Empty => '.',
+            Item::Robot(dir) => dir.to_char(),
+            Item::Tumbling => 'X',
+        }
+    }
+}
+
+impl Item {
+    fn get_dir(&self) -> Option<Direction> {
+        match self {
+            Item::Robot(d) => Some(*d),
+            _ => None,
+        }
+    }
+}
+
+pub fn get_maze(input: &[i64]) -> Maze {
+    let mut comp = get_computer(input, vec![]);
+
+    let mut camera = String::new();
+    loop {
+        match comp.run() {
+            Signal::ProducedOutput => camera.push(comp.get_output().unwrap() as u8 as char),
+            Signal::Halt => break,
+            _ => {}
+        }
+    }
+    Maze {
+        grid: Grid::parse(&camera),
+    }
+}
+
+fn sum_of_alignment(maze: &Maze) -> i64 {
+    maze.grid
+        .iter()
+        .filter(|(p, _)| maze.is_intersection(*p))
+        .map(|((x, y), _)| x * y)
+        .sum()
+}
+
+fn get_uncompressed_path(maze: &Maze) -> Vec<String> {
+    // get the position of the robot
+    let (mut pos, mut dir) = maze.robot();
+
+    let mut commands = vec![];
+
+    'outer: loop {
+        let mut count = 0;
+        'inner: loop {
+            let next = dir.step(pos, Axes::YDown);
+            if maze.is_scaffold(next) {
+                count += 1;
+                pos = next;
+            } else {
+                if count > 0 {
+                    commands.push(count.to_string());
+                }
+                for (turn, inst) in [(Turn::Left, "L"), (Turn::Right, "R")].iter() {
+                    let d = dir.turn(*turn);
+                    if maze.is_scaffold(d.step(pos, Axes::YDown)) {
+                        commands.push(inst.to_string());
+                        dir = d;
+                        break 'inner;
+                    }
+                }
+
+                break 'outer;
+            }
+        }
+    }
+
+    commands
+}
+
+// the ASCII program woken up and fed `routine`
+fn start_routine(input: &[i64], routine: &Routine, video: bool) -> IntCodeComputer {
+    let mut input = input.to_vec();
+    input[0] = 2;
+
+    let instructions = routine
+        .to_input(video)
+        .chars()
+        .map(|x| x as u8 as i64)
+        .collect::<Vec<i64>>();
+
+    get_computer(&input, instructions)
+}
+
+// the first routine that fits in the robot's memory and walks the whole
+// scaffold when checked offline
+pub fn find_routine(maze: &Maze) -> Routine {
+    compress(&get_uncompressed_path(maze))
+        .into_iter()
+        .find(|routine| simulate(maze, routine).is_ok_and(|walk| walk.is_valid()))
+        .expect("no routine fits in the robot's memory")
+}
+
+fn collect_dust(input: &[i64], maze: &Maze) -> i64 {
+    let mut comp = start_routine(input, &find_routine(maze), false);
+
+    comp.run_till_signal(Signal::Halt);
+    comp.get_output().unwrap()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Vec<i64> {
+        parse_program(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> i64 {
+        sum_of_alignment(&get_maze(input))
+    }
+
+    fn part2(input: &Vec<i64>) -> i64 {
+        collect_dust(input, &get_maze(input))
+    }
+}
+
+solution::answer_tests!(Day17);
+
+#[cfg(test)]
+mod tests {
+    use super::{get_uncompressed_path, sum_of_alignment, Maze};
+    use grid::Grid;
+
+    fn maze(text: &str) -> Maze {
+        Maze {
+            grid: Grid::parse(text),
+        }
+    }
+
+    #[test]
+    fn alignment() {
+        let m = maze(
+            "
+..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^..",
+        );
+        assert_eq!(sum_of_alignment(&m), 76);
+    }
+
+    #[test]
+    fn path() {
+        let m = maze(
+            "
+#######...#####
+#.....#...#...#
+#.....#...#...#
+......#...#...#
+......#...###.#
+......#.....#.#
+^########...#.#
+......#.#...#.#
+......#########
+........#...#..
+....#########..
+....#...#......
+....#...#......
+....#...#......
+....#####......",
+        );
+        assert_eq!(
+            get_uncompressed_path(&m).join(","),
+            "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+        );
+    }
+}
@@ -1,299 +1,123 @@
-use intcode::{get_computer, Signal};
+use solution::Solution;
+use std::env;
 use std::error::Error;
-use std::fmt;
-use std::fs;
-
-fn get_input() -> Result<Vec<i64>, Box<dyn Error>> {
-    let s = fs::read_to_string("input")?;
-    Ok(s.split(',').filter_map(|x| x.parse::<i64>().ok()).collect())
-}
-
-struct Maze {
-    matrix: Vec<Vec<Item>>,
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+const USAGE: &str = "usage: day17 [input] [options]
+
+options:
+    --video                watch the robot's camera feed in the terminal
+    --fps <n>              frames shown per second with --video (default 30)
+    --frames <dir>         save every camera frame to <dir> as text and PPM";
+
+// size of a scaffold cell in the saved PPM frames
+const PPM_SCALE: usize = 8;
+
+struct Options {
+    input: String,
+    video: bool,
+    fps: u64,
+    frames: Option<PathBuf>,
 }
 
-impl fmt::Display for Maze {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for i in self.matrix.iter() {
-            for j in i {
-                write!(f, "{}", j.to_ascii())?
-            }
-            write!(f, "\n",)?
-        }
-        Ok(())
-    }
-}
-
-impl Maze {
-    fn point_in_bounds(&self, (x, y): (i64, i64)) -> bool {
-        x >= 0 && y >= 0 && x < self.matrix.len() as i64 && y < self.matrix[0].len() as i64
-    }
-
-    fn is_scaffold(&self, (x, y): (i64, i64)) -> bool {
-        self.matrix[x as usize][y as usize] == Item::Scaffold
-    }
-
-    fn is_intersection(&self, (x, y): (i64, i64)) -> bool {
-        use Item::*;
-
-        let (x, y) = (x as usize, y as usize);
-
-        self.matrix[x - 1][y] == Scaffold
-            && self.matrix[x + 1][y] == Scaffold
-            && self.matrix[x][y + 1] == Scaffold
-            && self.matrix[x][y - 1] == Scaffold
-    }
-
-    fn robot_position(&self) -> (i64, i64) {
-        for i in 1..(self.matrix.len() - 1) {
-            for j in 1..(self.matrix[0].len() - 1) {
-                match self.matrix[i][j] {
-                    Item::Robot(_) => return (i as i64, j as i64),
-                    _ => {}
+fn parse_args(args: &[String]) -> Result<Options, Box<dyn Error>> {
+    let mut opts = Options {
+        input: concat!(env!("CARGO_MANIFEST_DIR"), "/input").to_string(),
+        video: false,
+        fps: 30,
+        frames: None,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--video" => opts.video = true,
+            "--fps" | "--frames" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", arg))?;
+                if arg == "--fps" {
+                    opts.fps = match value.parse() {
+                        Ok(n) if n > 0 => n,
+                        _ => return Err(format!("bad frame rate {}", value).into()),
+                    };
+                } else {
+                    opts.frames = Some(PathBuf::from(value));
                 }
             }
+            _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg).into()),
+            _ => opts.input = arg.clone(),
         }
-
-        panic!("this shouldn't happen");
     }
-}
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+    Ok(opts)
 }
 
-impl Direction {
-    fn from_int(i: i64) -> Direction {
-        use Direction::*;
-
-        match i as u8 as char {
-            '^' => Up,
-            '>' => Right,
-            '<' => Left,
-            'v' => Down,
-            _ => panic!("shouldn't happen"),
-        }
-    }
-
-    fn to_char(&self) -> char {
-        use Direction::*;
-        match self {
-            Up => '^',
-            Right => '>',
-            Left => '<',
-            Down => 'v',
-        }
-    }
-    fn get_delta(&self) -> (i64, i64) {
-        use Direction::*;
-        match self {
-            Down => (1, 0),
-            Up => (-1, 0),
-            Right => (0, 1),
-            Left => (0, -1),
-        }
-    }
-
-    fn move_point(&self, (x, y): (i64, i64)) -> (i64, i64) {
-        let delta = self.get_delta();
-        (x + delta.0, y + delta.1)
-    }
-
-    fn other_directions(&self) -> Vec<Direction> {
-        use Direction::*;
-
-        match self {
-            Up => vec![Left, Right],
-            Down => vec![Left, Right],
-            Right => vec![Up, Down],
-            Left => vec![Up, Down],
-        }
-    }
-
-    fn get_turn_instructions(&self, to_dir: Direction) -> Vec<String> {
-        use Direction::*;
-        let insts = match self {
-            Up => match to_dir {
-                Up => vec![],
-                Left => vec!["L"],
-                Right => vec!["R"],
-                Down => vec!["R", "R"],
-            },
-            Down => match to_dir {
-                Up => vec!["R", "R"],
-                Left => vec!["R"],
-                Right => vec!["L"],
-                Down => vec![],
-            },
-            Left => match to_dir {
-                Up => vec!["R"],
-                Left => vec![],
-                Right => vec!["R", "R"],
-                Down => vec!["L"],
-            },
-            Right => match to_dir {
-                Up => vec!["L"],
-                Left => vec!["R", "R"],
-                Right => vec![],
-                Down => vec!["R"],
-            },
-        };
-
-        insts.iter().map(|x| x.to_string()).collect()
-    }
+fn save_frame(dir: &Path, n: usize, maze: &day17::Maze) -> Result<(), Box<dyn Error>> {
+    fs::write(dir.join(format!("frame-{:04}.txt", n)), maze.to_string())?;
+    let mut ppm = BufWriter::new(File::create(dir.join(format!("frame-{:04}.ppm", n)))?);
+    maze.write_ppm(&mut ppm, PPM_SCALE)?;
+    Ok(())
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-enum Item {
-    Scaffold,
-    Empty,
-    Robot(Direction),
-}
-
-impl Item {
-    fn from_int(i: i64) -> Item {
-        match i {
-            35 => Item::Scaffold,
-            46 => Item::Empty,
-            _ => Item::Robot(Direction::from_int(i)),
-        }
+// runs the routine with the video feed on, showing and saving the frames
+fn watch(input: &[i64], opts: &Options) -> Result<i64, Box<dyn Error>> {
+    if let Some(dir) = &opts.frames {
+        fs::create_dir_all(dir)?;
     }
 
-    fn to_ascii(&self) -> char {
-        match self {
-            Item::Scaffold => '#',
-            Item::Empty => '.',
-            Item::Robot(dir) => dir.to_char(),
-        }
-    }
+    let routine = day17::find_routine(&day17::get_maze(input));
+    let delay = Duration::from_millis(1000 / opts.fps);
+    let mut frames = 0;
+    let mut saved = Ok(());
 
-    fn get_dir(&self) -> Option<Direction> {
-        match self {
-            Item::Robot(d) => Some(*d),
-            _ => None,
+    let dust = day17::watch(input, &routine, |maze| {
+        if opts.video {
+            // clear the terminal and draw from the top
+            print!("\x1b[2J\x1b[H{}", maze);
+            thread::sleep(delay);
         }
-    }
-}
-
-fn get_maze() -> Maze {
-    let input = get_input().unwrap();
-
-    let mut comp = get_computer(&input, vec![]);
-
-    let mut maze: Vec<Vec<Item>> = vec![];
-    let mut temp: Vec<Item> = vec![];
-    loop {
-        match comp.run() {
-            Signal::ProducedOutput => {
-                let out = comp.get_output().unwrap();
-                match out {
-                    10 => {
-                        maze.push(temp.clone());
-                        temp.clear();
-                    }
-                    _ => temp.push(Item::from_int(out)),
-                }
-            }
-            Signal::Halt => break,
-            _ => {}
+        if let (Some(dir), Ok(())) = (&opts.frames, &saved) {
+            saved = save_frame(dir, frames, maze);
         }
-    }
-    maze.pop(); // there is an extra new line at the end
-    Maze { matrix: maze }
-}
-
-fn sum_of_alignment(maze: &Maze) -> i64 {
-    let mut sm = 0;
+        frames += 1;
+    })?;
+    saved?;
 
-    for i in 1..(maze.matrix.len() - 1) {
-        for j in 1..(maze.matrix[0].len() - 1) {
-            let (i, j) = (i as i64, j as i64);
-            if maze.is_scaffold((i, j)) && maze.is_intersection((i, j)) {
-                sm += i * j;
-            }
-        }
+    if let Some(dir) = &opts.frames {
+        println!("saved {} frames to {}", frames, dir.display());
     }
-
-    sm
+    Ok(dust)
 }
 
-fn get_uncompressed_path(maze: &Maze) -> Vec<String> {
-    // get the position of the robot
-    let mut pos = maze.robot_position();
-
-    let mut commands = vec![];
-    let mut dir = maze.matrix[pos.0 as usize][pos.1 as usize]
-        .get_dir()
-        .unwrap();
-
-    'outer: loop {
-        let mut count = 0;
-        'inner: loop {
-            let (x1, y1) = dir.move_point(pos);
-            if maze.point_in_bounds((x1, y1)) && maze.is_scaffold((x1, y1)) {
-                count += 1;
-                pos = (x1, y1);
-            } else {
-                if count > 0 {
-                    commands.push(count.to_string());
-                }
-                for d in dir.other_directions() {
-                    let (x1, y1) = d.move_point(pos);
-                    if maze.point_in_bounds((x1, y1)) && maze.is_scaffold((x1, y1)) {
-                        let move_inst = dir.get_turn_instructions(d);
-                        commands.extend_from_slice(&move_inst);
-                        dir = d;
-                        break 'inner;
-                    }
-                }
-
-                break 'outer;
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let opts = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}\n\n{}", e, USAGE);
+        process::exit(1);
+    });
+
+    let raw = fs::read_to_string(&opts.input).unwrap();
+    let input = day17::Day17::parse(&raw);
+
+    if opts.video || opts.frames.is_some() {
+        match watch(&input, &opts) {
+            Ok(dust) => println!("Part 2: {}", dust),
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
             }
         }
+        return;
     }
 
-    commands
-}
-
-fn part2(maze: &Maze) -> i64 {
-    let path = get_uncompressed_path(&maze);
-
-    // solve this by hand using the path above, it pretty easy
-
-    // A,B,B,A,B,C,A,C,B,C
-    // A = L,4,L,6,L,8,L,12
-    // B = L,8,R,12,L,12
-    // C = R,12,L,6,L,6,L,8
-
-    let mut input = get_input().unwrap();
-    input[0] = 2;
-
-    let instructions =
-        "A,B,B,A,B,C,A,C,B,C\nL,4,L,6,L,8,L,12\nL,8,R,12,L,12\nR,12,L,6,L,6,L,8\nn\n";
-    let instructions = instructions
-        .chars()
-        .map(|x| x as u8 as i64)
-        .collect::<Vec<i64>>();
-
-    let mut comp = get_computer(&input, instructions);
-
-    comp.run_till_signal(Signal::Halt);
-    comp.get_output().unwrap()
-}
-
-fn main() {
-    let maze = get_maze();
-    println!("{}", maze);
-
-    let part1 = sum_of_alignment(&maze);
-    assert_eq!(part1, 6448);
-    println!("Part1: {:?}", part1);
+    println!("{}", day17::get_maze(&input));
 
-    let part2 = part2(&maze);
-    assert_eq!(part2, 914900);
-    println!("Part2: {:?}", part2);
+    println!("Part 1: {}", day17::Day17::part1(&input));
+    println!("Part 2: {}", day17::Day17::part2(&input));
 }
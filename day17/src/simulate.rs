@@ -0,0 +1,139 @@
+use crate::{Item, Maze, Routine};
+use grid::{Axes, Direction, Point, Turn};
+use std::collections::HashSet;
+
+// where a routine left the robot
+#[derive(Debug)]
+pub struct Walk {
+    pub position: Point,
+    pub direction: Direction,
+    // the first step that missed the scaffold, the robot stops there
+    pub fell_off: Option<Point>,
+    // scaffold the robot never got to, row by row
+    pub unvisited: Vec<Point>,
+}
+
+impl Walk {
+    // true when the robot stayed on the scaffold and visited all of it
+    pub fn is_valid(&self) -> bool {
+        self.fell_off.is_none() && self.unvisited.is_empty()
+    }
+}
+
+// walks `routine` on the maze from where the robot starts, without the
+// Intcode program. fails on commands the robot doesn't know.
+pub fn simulate(maze: &Maze, routine: &Routine) -> Result<Walk, String> {
+    let (start, mut direction) = maze.robot();
+    let mut position = start;
+    let mut visited = HashSet::new();
+    visited.insert(start);
+
+    let mut fell_off = None;
+    'walk: for &f in routine.main.iter() {
+        let function = routine
+            .functions
+            .get(f)
+            .ok_or_else(|| format!("the main routine calls a missing function {}", f))?;
+
+        for command in function.iter() {
+            match command.as_str() {
+                "L" => direction = direction.turn(Turn::Left),
+                "R" => direction = direction.turn(Turn::Right),
+                _ => {
+                    let steps: usize = command
+                        .parse()
+                        .map_err(|_| format!("unknown command {:?}", command))?;
+                    for _ in 0..steps {
+                        let next = direction.step(position, Axes::YDown);
+                        if !maze.is_scaffold(next) && next != start {
+                            fell_off = Some(next);
+                            break 'walk;
+                        }
+                        position = next;
+                        visited.insert(position);
+                    }
+                }
+            }
+        }
+    }
+
+    let unvisited = maze
+        .grid
+        .iter()
+        .filter(|(p, item)| **item == Item::Scaffold && !visited.contains(p))
+        .map(|(p, _)| p)
+        .collect();
+
+    Ok(Walk {
+        position,
+        direction,
+        fell_off,
+        unvisited,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simulate;
+    use crate::{Maze, Routine};
+    use grid::{Direction, Grid};
+
+    fn commands(s: &str) -> Vec<String> {
+        s.split(',').map(String::from).collect()
+    }
+
+    fn routine(main: &[usize], functions: &[&str]) -> Routine {
+        Routine {
+            main: main.to_vec(),
+            functions: functions.iter().map(|f| commands(f)).collect(),
+        }
+    }
+
+    fn maze() -> Maze {
+        Maze {
+            grid: Grid::parse(
+                "
+#######...#####
+#.....#...#...#
+#.....#...#...#
+......#...#...#
+......#...###.#
+......#.....#.#
+^########...#.#
+......#.#...#.#
+......#########
+........#...#..
+....#########..
+....#...#......
+....#...#......
+....#...#......
+....#####......",
+            ),
+        }
+    }
+
+    #[test]
+    fn example_routine() {
+        let walk = simulate(
+            &maze(),
+            &routine(&[0, 1, 2, 1, 0, 2], &["R,8,R,8", "R,4,R,4,R,8", "L,6,L,2"]),
+        )
+        .unwrap();
+        assert!(walk.is_valid());
+        assert_eq!((walk.position, walk.direction), ((0, 2), Direction::Down));
+    }
+
+    #[test]
+    fn bad_routines() {
+        let walk = simulate(&maze(), &routine(&[0], &["R,9"])).unwrap();
+        assert_eq!(walk.fell_off, Some((9, 6)));
+        assert_eq!(walk.position, (8, 6));
+
+        let walk = simulate(&maze(), &routine(&[0], &["R,8"])).unwrap();
+        assert_eq!(walk.fell_off, None);
+        assert_eq!(walk.unvisited.len(), 68);
+
+        assert!(simulate(&maze(), &routine(&[1], &["R,8"])).is_err());
+        assert!(simulate(&maze(), &routine(&[0], &["F"])).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{start_routine, Item, Maze, Routine};
+use grid::{Cell, Grid};
+use intcode::Signal;
+use std::io::{self, Write};
+
+impl Maze {
+    // a camera frame, None for the prompts the program prints in between
+    fn from_frame(text: &str) -> Option<Maze> {
+        let text = text.trim();
+        let is_map = text
+            .chars()
+            .all(|c| c == '\n' || Item::from_char(c).is_some());
+        if text.is_empty() || !is_map {
+            return None;
+        }
+
+        Some(Maze {
+            grid: Grid::parse(text),
+        })
+    }
+
+    // the maze as a binary PPM image
+    pub fn write_ppm<W: Write>(&self, out: &mut W, scale: usize) -> io::Result<()> {
+        grid::write_ppm(out, &self.grid, scale, |item| match item {
+            Item::Empty => (0, 0, 0),
+            Item::Scaffold => (160, 160, 160),
+            Item::Robot(_) => (255, 64, 64),
+            Item::Tumbling => (255, 255, 0),
+        })
+    }
+}
+
+// runs `routine` with the continuous video feed on, calling `show` with every
+// frame the camera sends. returns the dust collected, or what went wrong if
+// the robot stopped early.
+pub fn watch<F>(input: &[i64], routine: &Routine, mut show: F) -> Result<i64, String>
+where
+    F: FnMut(&Maze),
+{
+    let mut comp = start_routine(input, routine, true);
+
+    let mut text = String::new();
+    loop {
+        match comp.run() {
+            Signal::ProducedOutput => {
+                let out = comp.get_output().unwrap();
+                // the only output outside of ascii is the dust collected
+                if out > 127 {
+                    return Ok(out);
+                }
+
+                text.push(out as u8 as char);
+                // frames end with a blank line
+                if text.ends_with("\n\n") {
+                    if let Some(maze) = Maze::from_frame(&text) {
+                        show(&maze);
+                    }
+                    text.clear();
+                }
+            }
+            Signal::Halt => return Err("the robot stopped without reporting the dust".to_string()),
+            Signal::NeedsInput => {
+                return Err("the robot wants more input than the routine gives".to_string())
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::watch;
+    use crate::{Maze, Routine};
+
+    #[test]
+    fn frames() {
+        assert!(Maze::from_frame("Main:\nFunction A:\n\n").is_none());
+
+        let maze = Maze::from_frame("\n#.^\n##X\n\n").unwrap();
+        assert_eq!(maze.to_string(), "#.^\n##X\n");
+
+        let mut ppm = vec![];
+        maze.write_ppm(&mut ppm, 2).unwrap();
+        assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 6 * 4 * 3);
+    }
+
+    #[test]
+    fn runs_out_of_input() {
+        // reads input forever
+        let program = [1, 0, 0, 0, 3, 9, 1105, 1, 4, 0];
+        let routine = Routine {
+            main: vec![0],
+            functions: vec![vec!["R".to_string()]],
+        };
+        let err = watch(&program, &routine, |_| {}).unwrap_err();
+        assert!(err.contains("more input"));
+    }
+}
@@ -0,0 +1,80 @@
+use intcode::{get_computer, parse_program, Search, Signal};
+use solution::Solution;
+
+fn restore_gravity_assist(input: &[i64]) -> i64 {
+    let mut computer = get_computer(input, vec![]);
+    computer.store_value_at_pos(1, 12);
+    computer.store_value_at_pos(2, 2);
+    computer.run_till_signal(Signal::Halt);
+    computer.get_value_at_pos(0)
+}
+
+fn find_noun_and_verb(input: &[i64]) -> Option<i64> {
+    let found = Search::new(input)
+        .patch(1, 0..100)
+        .patch(2, 0..100)
+        .limit(1)
+        .run(|comp, _| comp.memory()[0] == 19690720);
+    found.first().map(|values| 100 * values[0] + values[1])
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Vec<i64> {
+        parse_program(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> i64 {
+        restore_gravity_assist(input)
+    }
+
+    fn part2(input: &Vec<i64>) -> i64 {
+        find_noun_and_verb(input).expect("no noun and verb give 19690720")
+    }
+}
+
+solution::answer_tests!(Day2);
+
+#[cfg(test)]
+mod tests {
+    use super::{find_noun_and_verb, Day2};
+    use intcode::{get_computer, Signal};
+    use solution::Solution;
+
+    fn run(prog: &[i64]) -> Vec<i64> {
+        let mut computer = get_computer(prog, vec![]);
+        computer.run_till_signal(Signal::Halt);
+        computer.memory()[..prog.len()].to_vec()
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(
+            run(&[1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]),
+            vec![3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50]
+        );
+        assert_eq!(run(&[1, 0, 0, 0, 99]), vec![2, 0, 0, 0, 99]);
+        assert_eq!(run(&[2, 3, 0, 3, 99]), vec![2, 3, 0, 6, 99]);
+        assert_eq!(run(&[2, 4, 4, 5, 99, 0]), vec![2, 4, 4, 5, 99, 9801]);
+        assert_eq!(
+            run(&[1, 1, 1, 4, 99, 5, 6, 0, 99]),
+            vec![30, 1, 1, 4, 2, 5, 6, 0, 99]
+        );
+    }
+
+    #[test]
+    fn no_noun_and_verb() {
+        assert_eq!(find_noun_and_verb(&[1, 0, 0, 0, 99]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "no noun and verb")]
+    fn no_answer() {
+        Day2::part2(&vec![1, 0, 0, 0, 99]);
+    }
+}
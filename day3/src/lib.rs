@@ -0,0 +1,194 @@
+use solution::Solution;
+use std::cmp::min;
+
+#[derive(Clone, Copy)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Clone, Copy)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Point {
+    fn manhattan_distance(&self) -> i64 {
+        // from origin
+        self.x.abs() + self.y.abs()
+    }
+}
+
+impl Line {
+    fn length(&self) -> i64 {
+        (self.start.x - self.end.x).abs() + (self.start.y - self.end.y).abs()
+    }
+
+    fn distance_from_start(&self, p: Point) -> i64 {
+        // assume p is on this line
+        Line {
+            start: self.start,
+            end: p,
+        }
+        .length()
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    fn points(&self) -> (Point, Point) {
+        (self.start, self.end)
+    }
+
+    fn sorted_points(&self) -> (Point, Point) {
+        let (p1, p2) = self.points();
+        if p1.y < p2.y || p1.x < p2.x {
+            (p1, p2)
+        } else {
+            (p2, p1)
+        }
+    }
+}
+
+fn get_lines(w: &str) -> Vec<Line> {
+    let mut last_pos = Point { x: 0, y: 0 };
+    w.split(",")
+        .map(|x| {
+            let mut chars = x.chars();
+            let direction = chars.next().unwrap();
+            let length = chars.collect::<String>().parse::<i64>().unwrap();
+
+            let new_point = match direction {
+                'R' => Point {
+                    x: last_pos.x + length,
+                    y: last_pos.y,
+                },
+                'L' => Point {
+                    x: last_pos.x - length,
+                    y: last_pos.y,
+                },
+                'U' => Point {
+                    x: last_pos.x,
+                    y: last_pos.y + length,
+                },
+                'D' => Point {
+                    x: last_pos.x,
+                    y: last_pos.y - length,
+                },
+                _ => panic!("wup"),
+            };
+            let ret = Line {
+                start: last_pos,
+                end: new_point,
+            };
+            last_pos = new_point;
+            ret
+        })
+        .collect()
+}
+
+fn get_input(s: &str) -> (Vec<Line>, Vec<Line>) {
+    let mut lines: Vec<Vec<Line>> = s.trim().split("\n").map(get_lines).collect();
+
+    let l2 = lines.remove(1);
+    let l1 = lines.remove(0);
+    (l1, l2)
+}
+
+fn lines_intersect(l1: Line, l2: Line) -> Option<Point> {
+    if l1.is_vertical() == l2.is_vertical() {
+        // parallel, the wires never overlap in the inputs
+        return None;
+    }
+    let (v, h) = if l1.is_vertical() { (l1, l2) } else { (l2, l1) };
+
+    let (m1, m2) = v.sorted_points();
+    let (n1, n2) = h.sorted_points();
+
+    if n1.x <= m1.x && n2.x >= m1.x && n1.y <= m2.y && n1.y >= m1.y {
+        return Some(Point { x: m1.x, y: n1.y });
+    }
+    None
+}
+
+// returns the smallest manhattan distance and the smallest combined wire
+// length over all the intersections
+fn closest_intersections(wire1: &[Line], wire2: &[Line]) -> (i64, i64) {
+    let mut min_manhatten = i64::MAX;
+    let mut min_distance = i64::MAX;
+
+    // runnign lengths for w1 and w2
+    let mut w1_rl = 0;
+    let mut w2_rl = 0;
+
+    // the input is small enough, lets brute force
+    for line1 in wire1 {
+        for line2 in wire2 {
+            // both wires start at the origin, that doesn't count
+            let crossing = lines_intersect(*line1, *line2).filter(|pt| pt.manhattan_distance() > 0);
+            if let Some(pt) = crossing {
+                // part 1
+                min_manhatten = min(min_manhatten, pt.manhattan_distance());
+
+                // part 2
+                let d1 = line1.distance_from_start(pt);
+                let d2 = line2.distance_from_start(pt);
+                min_distance = min(min_distance, w1_rl + d1 + w2_rl + d2);
+            }
+            w2_rl += line2.length();
+        }
+        w1_rl += line1.length();
+        w2_rl = 0;
+    }
+
+    (min_manhatten, min_distance)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = (Vec<Line>, Vec<Line>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Input {
+        get_input(input)
+    }
+
+    fn part1((wire1, wire2): &Self::Input) -> i64 {
+        closest_intersections(wire1, wire2).0
+    }
+
+    fn part2((wire1, wire2): &Self::Input) -> i64 {
+        closest_intersections(wire1, wire2).1
+    }
+}
+
+solution::answer_tests!(Day3);
+
+#[cfg(test)]
+mod tests {
+    use super::{closest_intersections, get_input};
+
+    fn solve(input: &str) -> (i64, i64) {
+        let (wire1, wire2) = get_input(input);
+        closest_intersections(&wire1, &wire2)
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(solve("R8,U5,L5,D3\nU7,R6,D4,L4"), (6, 30));
+        assert_eq!(
+            solve("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83"),
+            (159, 610)
+        );
+        assert_eq!(
+            solve(
+                "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7"
+            ),
+            (135, 410)
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use solution::Solution;
+use std::ops::Range;
+
+fn reverse_digit_iter(mut d: u32) -> impl Iterator<Item = u32> {
+    ::std::iter::from_fn(move || {
+        if d == 0 {
+            None
+        } else {
+            let n = d % 10;
+            d /= 10;
+            Some(n)
+        }
+    })
+}
+
+fn is_decreasing(d: u32) -> bool {
+    let mut it = reverse_digit_iter(d);
+    let mut last = it.next().unwrap();
+
+    for i in it {
+        if i > last {
+            return false;
+        }
+        last = i;
+    }
+    true
+}
+
+fn is_password(d: u32) -> (bool, bool) {
+    if !is_decreasing(d) {
+        return (false, false);
+    }
+
+    let mut is_password = false;
+    let mut is_password_strict = false;
+
+    let mut it = reverse_digit_iter(d);
+    let mut last = it.next().unwrap();
+    let mut num_adjacent = 1;
+
+    for i in it {
+        if i == last {
+            num_adjacent += 1;
+            is_password = true;
+        } else {
+            if num_adjacent == 2 {
+                is_password_strict = true;
+            }
+            num_adjacent = 1;
+        }
+        last = i;
+    }
+    if num_adjacent == 2 {
+        is_password_strict = true;
+    }
+
+    (is_password, is_password_strict)
+}
+
+fn get_range(input: &str) -> Range<u32> {
+    let mut split = input.trim().split('-');
+    let start = split.next().unwrap().parse::<u32>().unwrap();
+    let end = split.next().unwrap().parse::<u32>().unwrap();
+    start..end
+}
+
+fn count_passwords(range: Range<u32>) -> (u64, u64) {
+    let mut count1: u64 = 0;
+    let mut count2: u64 = 0;
+    range.for_each(|x| {
+        let (p1, p2) = is_password(x);
+        if p1 {
+            count1 += 1;
+        }
+        if p2 {
+            count2 += 1;
+        }
+    });
+    (count1, count2)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Range<u32>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Range<u32> {
+        get_range(input)
+    }
+
+    fn part1(range: &Range<u32>) -> u64 {
+        count_passwords(range.clone()).0
+    }
+
+    fn part2(range: &Range<u32>) -> u64 {
+        count_passwords(range.clone()).1
+    }
+}
+
+solution::answer_tests!(Day4);
+
+#[cfg(test)]
+mod tests {
+    use super::is_password;
+
+    #[test]
+    fn examples() {
+        assert!(is_password(111111).0);
+        assert!(!is_password(223450).0);
+        assert!(!is_password(123789).0);
+
+        assert!(is_password(112233).1);
+        assert!(!is_password(123444).1);
+        assert!(is_password(111122).1);
+    }
+}
@@ -0,0 +1,56 @@
+use intcode::{get_computer, parse_program, Signal};
+use solution::Solution;
+
+fn diagnostic_code(input: &[i64], system_id: i64) -> i64 {
+    let mut c = get_computer(input, vec![system_id]);
+    c.run_till_signal(Signal::Halt);
+    c.get_output().unwrap()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Vec<i64> {
+        parse_program(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> i64 {
+        diagnostic_code(input, 1)
+    }
+
+    fn part2(input: &Vec<i64>) -> i64 {
+        diagnostic_code(input, 5)
+    }
+}
+
+solution::answer_tests!(Day5);
+
+#[cfg(test)]
+mod tests {
+    use super::diagnostic_code;
+
+    #[test]
+    fn compare_to_eight() {
+        let prog = [
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0,
+            0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4,
+            20, 1105, 1, 46, 98, 99,
+        ];
+        assert_eq!(diagnostic_code(&prog, 7), 999);
+        assert_eq!(diagnostic_code(&prog, 8), 1000);
+        assert_eq!(diagnostic_code(&prog, 9), 1001);
+    }
+
+    #[test]
+    fn equal_and_less_than() {
+        // position mode, then immediate mode
+        assert_eq!(diagnostic_code(&[3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8], 8), 1);
+        assert_eq!(diagnostic_code(&[3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8], 8), 0);
+        assert_eq!(diagnostic_code(&[3, 3, 1108, -1, 8, 3, 4, 3, 99], 5), 0);
+        assert_eq!(diagnostic_code(&[3, 3, 1107, -1, 8, 3, 4, 3, 99], 5), 1);
+    }
+}
@@ -0,0 +1,114 @@
+use solution::Solution;
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+
+type Orbits = HashMap<String, Vec<String>>;
+type ReverseOrbits = HashMap<String, String>;
+
+fn parse_input(input: &str) -> Result<(Orbits, ReverseOrbits), Box<dyn Error>> {
+    let mut orbits: Orbits = HashMap::with_capacity(2000);
+    let mut orbits_rev: ReverseOrbits = HashMap::with_capacity(2000);
+
+    input.trim().split('\n').for_each(|line| {
+        let mut objs = line.trim().split(')');
+        let parent = objs.next().unwrap().to_string();
+        let child = objs.next().unwrap().to_string();
+        orbits
+            .entry(parent.clone())
+            .or_default()
+            .push(child.clone());
+        orbits_rev.entry(child).or_insert(parent);
+    });
+
+    Ok((orbits, orbits_rev))
+}
+
+fn orbit_count_checksum(m: &Orbits) -> u32 {
+    let mut queue = VecDeque::new();
+    queue.push_back(("COM", 1));
+    let mut count: u32 = 0;
+
+    while let Some((parent, cur_count)) = queue.pop_back() {
+        if let Some(childs) = m.get(parent) {
+            for child in childs {
+                count += cur_count;
+                queue.push_back((child, cur_count + 1));
+            }
+        }
+    }
+    count
+}
+
+fn parents<'a>(m: &'a ReverseOrbits, from: &'a str) -> Vec<&'a str> {
+    let mut cur = from;
+    let mut parents = Vec::new();
+    while cur != "COM" {
+        if let Some(parent) = m.get(cur) {
+            parents.push(parent.as_str());
+            cur = parent;
+        }
+    }
+    parents
+}
+
+fn first_common_element(from: Vec<&str>, to: Vec<&str>) -> Option<(usize, usize)> {
+    let mut hm: HashMap<&str, usize> = HashMap::new();
+    for (i, item) in to.into_iter().enumerate() {
+        hm.insert(item, i);
+    }
+    for (i, f) in from.iter().enumerate() {
+        if let Some(j) = hm.get(f) {
+            return Some((i, *j));
+        }
+    }
+    None
+}
+
+fn min_orbital_jumps(m: &ReverseOrbits, from: &str, to: &str) -> usize {
+    let from_parents = parents(m, from);
+    let to_parents = parents(m, to);
+
+    let (i, j) = first_common_element(from_parents, to_parents).unwrap();
+    i + j
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Orbits, ReverseOrbits);
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input).unwrap()
+    }
+
+    fn part1((orbits, _): &Self::Input) -> u32 {
+        orbit_count_checksum(orbits)
+    }
+
+    fn part2((_, orbits_rev): &Self::Input) -> usize {
+        min_orbital_jumps(orbits_rev, "YOU", "SAN")
+    }
+}
+
+solution::answer_tests!(Day6);
+
+#[cfg(test)]
+mod tests {
+    use super::{min_orbital_jumps, orbit_count_checksum, parse_input};
+
+    const ORBITS: &str = "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L";
+
+    #[test]
+    fn checksum() {
+        let (orbits, _) = parse_input(ORBITS).unwrap();
+        assert_eq!(orbit_count_checksum(&orbits), 42);
+    }
+
+    #[test]
+    fn jumps_to_santa() {
+        let (_, orbits_rev) = parse_input(&format!("{}\nK)YOU\nI)SAN", ORBITS)).unwrap();
+        assert_eq!(min_orbital_jumps(&orbits_rev, "YOU", "SAN"), 4);
+    }
+}
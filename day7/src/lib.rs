@@ -0,0 +1,50 @@
+use intcode::{best_phase_setting, parse_program, Topology};
+use solution::Solution;
+
+fn max_feedback_signal(input: &[i64]) -> i64 {
+    let (_, signal) = best_phase_setting(input, &[5, 6, 7, 8, 9], 5, Topology::Feedback).unwrap();
+    signal
+}
+
+fn max_signal(input: &[i64]) -> i64 {
+    let (_, signal) = best_phase_setting(input, &[0, 1, 2, 3, 4], 5, Topology::Serial).unwrap();
+    signal
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Vec<i64> {
+        parse_program(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> i64 {
+        max_signal(input)
+    }
+
+    fn part2(input: &Vec<i64>) -> i64 {
+        max_feedback_signal(input)
+    }
+}
+
+solution::answer_tests!(Day7);
+
+#[cfg(test)]
+mod tests {
+    use super::{max_feedback_signal, max_signal};
+
+    #[test]
+    fn five_amplifiers() {
+        // passes on ten times its input plus its phase, so the best signal
+        // spells out the phase of every amplifier in order
+        let prog = [
+            3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+        ];
+        assert_eq!(max_signal(&prog), 43210);
+        assert_eq!(max_feedback_signal(&prog), 98765);
+    }
+}
@@ -0,0 +1,186 @@
+use grid::{read_letters, Grid};
+use solution::Solution;
+use std::fmt::{self, Display};
+
+const LAYER_WIDTH: usize = 25;
+const LAYER_HEIGHT: usize = 6;
+
+#[derive(Clone)]
+struct Layer {
+    data: Vec<Vec<char>>,
+}
+
+impl Layer {
+    fn new(layer: &[char], width: usize) -> Self {
+        let mut data = vec![];
+        for i in (0..layer.len()).step_by(width) {
+            data.push(layer[i..i + width].to_owned());
+        }
+
+        Layer { data }
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &Vec<char>> {
+        self.data.iter()
+    }
+
+    fn rows_mut(&mut self) -> impl Iterator<Item = &mut Vec<char>> {
+        self.data.iter_mut()
+    }
+
+    fn value_at(&self, row: usize, column: usize) -> char {
+        self.data[row][column]
+    }
+
+    fn _set_value(&mut self, row: usize, column: usize, value: char) {
+        self.data[row][column] = value;
+    }
+
+    fn count_pixels(&self) -> (u32, u32, u32) {
+        let (mut zero, mut one, mut two) = (0, 0, 0);
+        for row in self.rows() {
+            for c in row {
+                match c {
+                    '0' => zero += 1,
+                    '1' => one += 1,
+                    '2' => two += 1,
+                    _ => {}
+                }
+            }
+        }
+        (zero, one, two)
+    }
+
+    // the letters drawn by the white pixels
+    fn read(&self) -> String {
+        let mut grid = Grid::new(self.data[0].len(), self.data.len(), false);
+        for (y, row) in self.rows().enumerate() {
+            for (x, c) in row.iter().enumerate() {
+                grid[(x as i64, y as i64)] = *c == '1';
+            }
+        }
+        read_letters(&grid, |white| *white)
+    }
+}
+
+impl Display for Layer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for c in row {
+                match c {
+                    '0' => write!(f, " ")?,
+                    '1' => write!(f, "*")?,
+                    _ => {}
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Image {
+    data: Vec<Layer>,
+}
+
+impl Image {
+    fn new(img: &[char], width: usize, height: usize) -> Self {
+        let mut data = vec![];
+        let step = height * width;
+
+        for i in (0..img.len()).step_by(step) {
+            let layer = &img[i..(i + step)];
+            data.push(Layer::new(layer, width));
+        }
+        Image { data }
+    }
+
+    fn layers(&self) -> impl Iterator<Item = &Layer> {
+        self.data.iter()
+    }
+
+    fn non_transparet_value_at(&self, i: usize, j: usize) -> Option<char> {
+        for layer in self.layers() {
+            let pixel = layer.value_at(i, j);
+            if pixel != '2' {
+                return Some(pixel);
+            }
+        }
+        None
+    }
+
+    fn flatten(&self) -> Layer {
+        let mut final_layer = self.layers().nth(0).unwrap().clone();
+
+        for (i, row) in final_layer.rows_mut().enumerate() {
+            for (j, c) in row.iter_mut().enumerate() {
+                if *c == '2' {
+                    *c = self.non_transparet_value_at(i, j).unwrap();
+                }
+            }
+        }
+
+        final_layer
+    }
+}
+
+fn get_image(input: &str) -> Image {
+    let input: Vec<char> = input.trim().chars().collect();
+    Image::new(&input, LAYER_WIDTH, LAYER_HEIGHT)
+}
+
+fn checksum(image: &Image) -> u32 {
+    let mut min_zeros = u32::MAX;
+    let mut one_times_two = 0;
+    for layer in image.layers() {
+        let (zero, one, two) = layer.count_pixels();
+        if zero < min_zeros {
+            one_times_two = one * two;
+            min_zeros = zero;
+        }
+    }
+    one_times_two
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Image;
+    type Part1 = u32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Image {
+        get_image(input)
+    }
+
+    fn part1(image: &Image) -> u32 {
+        checksum(image)
+    }
+
+    fn part2(image: &Image) -> String {
+        image.flatten().read()
+    }
+}
+
+solution::answer_tests!(Day8);
+
+#[cfg(test)]
+mod tests {
+    use super::{checksum, Image};
+
+    fn image(data: &str, width: usize, height: usize) -> Image {
+        Image::new(&data.chars().collect::<Vec<char>>(), width, height)
+    }
+
+    #[test]
+    fn layers() {
+        // layer 1 has no zeros, one 1 and one 2
+        assert_eq!(checksum(&image("123456789012", 3, 2)), 1);
+    }
+
+    #[test]
+    fn decode() {
+        let image = image("0222112222120000", 2, 2);
+        assert_eq!(image.flatten().to_string(), " *\n* \n");
+    }
+}
@@ -0,0 +1,59 @@
+use intcode::{get_computer, parse_program, Signal};
+use solution::Solution;
+
+fn boost_keycode(input: &[i64], mode: i64) -> i64 {
+    let mut c = get_computer(input, vec![mode]);
+    c.run_till_signal(Signal::ProducedOutput);
+    c.get_output().unwrap()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Vec<i64> {
+        parse_program(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> i64 {
+        boost_keycode(input, 1)
+    }
+
+    fn part2(input: &Vec<i64>) -> i64 {
+        boost_keycode(input, 2)
+    }
+}
+
+solution::answer_tests!(Day9);
+
+#[cfg(test)]
+mod tests {
+    use super::boost_keycode;
+    use intcode::{get_computer, Signal};
+
+    #[test]
+    fn quine() {
+        let prog = vec![
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        let mut c = get_computer(&prog, vec![]);
+        let mut output = vec![];
+        while c.run() == Signal::ProducedOutput {
+            output.push(c.get_output().unwrap());
+        }
+        assert_eq!(output, prog);
+    }
+
+    #[test]
+    fn large_numbers() {
+        let prog = [1102, 34915192, 34915192, 7, 4, 7, 99, 0];
+        assert_eq!(boost_keycode(&prog, 1).to_string().len(), 16);
+        assert_eq!(
+            boost_keycode(&[104, 1125899906842624, 99], 1),
+            1125899906842624
+        );
+    }
+}
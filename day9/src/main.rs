@@ -1,24 +1,14 @@
-use std::error::Error;
-use std::fs::File;
-use std::io::prelude::*;
-use intcode::{get_computer, Signal};
-
-
-fn get_input() -> Result<Vec<i64>, Box<dyn Error>> {
-    let mut f = File::open("input")?;
-    let mut s = String::new();
-    f.read_to_string(&mut s)?;
-
-    Ok(s.split(',').filter_map(|x| x.parse::<i64>().ok()).collect())
-}
+use solution::Solution;
+use std::env;
+use std::fs;
 
 fn main() {
-    let input: Vec<i64> = get_input().unwrap();
-    let mut c = get_computer(&input, vec![1]);
-    c.run_till_signal(Signal::ProducedOutput);
-    println!("Part1: {:?}", c.get_output().unwrap());
-
-    let mut c = get_computer(&input, vec![2]);
-    c.run_till_signal(Signal::ProducedOutput);
-    println!("Part2: {:?}", c.get_output().unwrap());
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| concat!(env!("CARGO_MANIFEST_DIR"), "/input").to_string());
+    let raw = fs::read_to_string(path).unwrap();
+    let input = day9::Day9::parse(&raw);
+
+    println!("Part 1: {}", day9::Day9::part1(&input));
+    println!("Part 2: {}", day9::Day9::part2(&input));
 }
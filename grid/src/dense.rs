@@ -0,0 +1,188 @@
+use crate::{neighbours, parse_cell, rows, Cell, Point};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// a fixed size grid with a value in every cell, (0, 0) is the top left
+#[derive(Clone, PartialEq, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (x, y): Point) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        if self.in_bounds(p) {
+            Some(p.1 as usize * self.width + p.0 as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(move |i| &mut self.cells[i])
+    }
+
+    // every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, v)| (((i % width) as i64, (i / width) as i64), v))
+    }
+
+    // the neighbours of `p` that are inside the grid
+    pub fn neighbours(&self, p: Point) -> impl Iterator<Item = (Point, &T)> {
+        neighbours(p).filter_map(move |n| self.get(n).map(|v| (n, v)))
+    }
+
+    // a grid built from the rows of a character map, all rows must be as
+    // long as the first one
+    pub fn parse(text: &str) -> Self
+    where
+        T: Cell,
+    {
+        let mut width = None;
+        let mut cells = vec![];
+        let mut height = 0;
+        for (y, row) in rows(text).enumerate() {
+            let before = cells.len();
+            for (x, c) in row.chars().enumerate() {
+                cells.push(parse_cell(c, (x as i64, y as i64)));
+            }
+
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(w) if w != len => panic!("row {} is {} long, expected {}", y, len, w),
+                _ => {}
+            }
+            height += 1;
+        }
+
+        Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", p.0, p.1))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", p.0, p.1))
+    }
+}
+
+// draws the grid back as a character map, every row ends with a newline
+impl<T: Cell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::Cell;
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl Cell for Tile {
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Tile::Open),
+                '#' => Some(Tile::Wall),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Tile::Open => '.',
+                Tile::Wall => '#',
+            }
+        }
+    }
+
+    const MAP: &str = "
+#..#
+.##.
+";
+
+    #[test]
+    fn parse_and_render() {
+        let grid: Grid<Tile> = Grid::parse(MAP);
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+        assert_eq!(grid[(1, 1)], Tile::Wall);
+        assert_eq!(grid.get((4, 0)), None);
+        assert_eq!(grid.to_string(), "#..#\n.##.\n");
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let mut grid: Grid<Tile> = Grid::parse(MAP);
+        grid[(0, 0)] = Tile::Open;
+
+        let walls = grid
+            .neighbours((0, 0))
+            .filter(|(_, t)| **t == Tile::Wall)
+            .count();
+        assert_eq!(walls, 0);
+        assert_eq!(grid.neighbours((0, 0)).count(), 2);
+        assert_eq!(grid.neighbours((1, 1)).count(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ragged_rows() {
+        let _: Grid<Tile> = Grid::parse("##\n#");
+    }
+}
@@ -0,0 +1,183 @@
+use crate::Point;
+
+// which way y grows. maps read from text grow downwards, the repair droid's
+// area in day 15 grows upwards.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Axes {
+    YDown,
+    YUp,
+}
+
+// a heading, the variants are in clockwise order
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+// a change of heading relative to the current one
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Turn {
+    Straight,
+    Left,
+    Right,
+    Around,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    fn from_index(i: usize) -> Direction {
+        Direction::ALL[i % 4]
+    }
+
+    pub fn turn(self, turn: Turn) -> Direction {
+        let quarters = match turn {
+            Turn::Straight => 0,
+            Turn::Right => 1,
+            Turn::Around => 2,
+            Turn::Left => 3,
+        };
+        Direction::from_index(self as usize + quarters)
+    }
+
+    pub fn left(self) -> Direction {
+        self.turn(Turn::Left)
+    }
+
+    pub fn right(self) -> Direction {
+        self.turn(Turn::Right)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn(Turn::Around)
+    }
+
+    // the turn that takes this heading to `to`
+    pub fn turn_to(self, to: Direction) -> Turn {
+        match (to as usize + 4 - self as usize) % 4 {
+            0 => Turn::Straight,
+            1 => Turn::Right,
+            2 => Turn::Around,
+            _ => Turn::Left,
+        }
+    }
+
+    pub fn delta(self, axes: Axes) -> Point {
+        let dy = match axes {
+            Axes::YDown => 1,
+            Axes::YUp => -1,
+        };
+        match self {
+            Direction::Up => (0, -dy),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, dy),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    // the point one step from `p` in this direction
+    pub fn step(self, (x, y): Point, axes: Axes) -> Point {
+        let (dx, dy) = self.delta(axes);
+        (x + dx, y + dy)
+    }
+
+    // how the vacuum robot's camera in day 17 draws it
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    // movement commands of the repair droid in day 15, north is up
+    pub fn from_droid_command(command: i64) -> Option<Direction> {
+        match command {
+            1 => Some(Direction::Up),
+            2 => Some(Direction::Down),
+            3 => Some(Direction::Left),
+            4 => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    pub fn droid_command(self) -> i64 {
+        match self {
+            Direction::Up => 1,
+            Direction::Down => 2,
+            Direction::Left => 3,
+            Direction::Right => 4,
+        }
+    }
+}
+
+impl Turn {
+    // turn instructions of the hull painting robot in day 11
+    pub fn from_robot_code(code: i64) -> Option<Turn> {
+        match code {
+            0 => Some(Turn::Left),
+            1 => Some(Turn::Right),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Axes, Direction, Turn};
+    use Direction::*;
+
+    #[test]
+    fn turning() {
+        assert_eq!(Up.right(), Right);
+        assert_eq!(Up.left(), Left);
+        assert_eq!(Left.right(), Up);
+        assert_eq!(Down.opposite(), Up);
+
+        for from in Direction::ALL.iter() {
+            for to in Direction::ALL.iter() {
+                assert_eq!(from.turn(from.turn_to(*to)), *to);
+            }
+        }
+        assert_eq!(Right.turn_to(Up), Turn::Left);
+        assert_eq!(Right.turn_to(Left), Turn::Around);
+    }
+
+    #[test]
+    fn axes() {
+        assert_eq!(Up.step((0, 0), Axes::YDown), (0, -1));
+        assert_eq!(Up.step((0, 0), Axes::YUp), (0, 1));
+        assert_eq!(Left.step((0, 0), Axes::YUp), (-1, 0));
+    }
+
+    #[test]
+    fn encodings() {
+        for dir in Direction::ALL.iter() {
+            assert_eq!(Direction::from_char(dir.to_char()), Some(*dir));
+            assert_eq!(
+                Direction::from_droid_command(dir.droid_command()),
+                Some(*dir)
+            );
+        }
+        assert_eq!(Turn::from_robot_code(0), Some(Turn::Left));
+    }
+}
@@ -0,0 +1,186 @@
+// Pictures of grids for saving to files. Every cell is drawn as a `scale`
+// pixels wide square, the caller says what color a cell is.
+use crate::Grid;
+use std::convert::TryFrom;
+use std::io::{self, Write};
+
+// red, green and blue
+pub type Rgb = (u8, u8, u8);
+
+// what can go wrong writing a GIF
+pub type GifError = gif::EncodingError;
+
+// the cells scaled up to pixels, row by row, `fill` where a grid smaller
+// than the picture has no cell
+fn pixels<T, P, F>(
+    grid: &Grid<T>,
+    (width, height): (usize, usize),
+    scale: usize,
+    fill: P,
+    pixel: F,
+) -> Vec<P>
+where
+    P: Copy,
+    F: Fn(&T) -> P,
+{
+    let mut pixels = Vec::with_capacity(width * height * scale * scale);
+    for y in 0..height * scale {
+        for x in 0..width * scale {
+            let cell = grid.get(((x / scale) as i64, (y / scale) as i64));
+            pixels.push(cell.map_or(fill, &pixel));
+        }
+    }
+    pixels
+}
+
+// a plain PBM image, black or white
+pub fn write_pbm<W, T, F>(out: &mut W, grid: &Grid<T>, scale: usize, black: F) -> io::Result<()>
+where
+    W: Write,
+    F: Fn(&T) -> bool,
+{
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    writeln!(out, "P1\n{} {}", width, height)?;
+
+    let bits = pixels(grid, (grid.width(), grid.height()), scale, false, black);
+    for row in bits.chunks(width.max(1)) {
+        let row: Vec<&str> = row.iter().map(|&b| if b { "1" } else { "0" }).collect();
+        writeln!(out, "{}", row.join(" "))?;
+    }
+    Ok(())
+}
+
+// a binary PPM image in full color
+pub fn write_ppm<W, T, F>(out: &mut W, grid: &Grid<T>, scale: usize, color: F) -> io::Result<()>
+where
+    W: Write,
+    F: Fn(&T) -> Rgb,
+{
+    let (width, height) = (grid.width(), grid.height());
+    writeln!(out, "P6\n{} {}\n255", width * scale, height * scale)?;
+
+    for (r, g, b) in pixels(grid, (width, height), scale, (0, 0, 0), color) {
+        out.write_all(&[r, g, b])?;
+    }
+    Ok(())
+}
+
+// an animated GIF that loops forever, showing every frame for `delay`
+// hundredths of a second. cells pick their color from `palette` by index.
+// the picture is as big as the largest frame, smaller frames are padded with
+// the first color of the palette.
+pub fn write_gif<W, T, F>(
+    out: W,
+    frames: &[Grid<T>],
+    palette: &[Rgb],
+    scale: usize,
+    delay: u16,
+    index: F,
+) -> Result<(), GifError>
+where
+    W: Write,
+    F: Fn(&T) -> u8,
+{
+    let size = frames
+        .iter()
+        .fold((0, 0), |(w, h), f| (w.max(f.width()), h.max(f.height())));
+    let pixels_across = |cells: usize| {
+        u16::try_from(cells.saturating_mul(scale)).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the picture is too big for a GIF",
+            )
+        })
+    };
+    let (w, h) = (pixels_across(size.0)?, pixels_across(size.1)?);
+    let palette: Vec<u8> = palette
+        .iter()
+        .flat_map(|&(r, g, b)| vec![r, g, b])
+        .collect();
+
+    let mut encoder = gif::Encoder::new(out, w, h, &palette)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    for grid in frames {
+        let indexed = pixels(grid, size, scale, 0, &index);
+        let mut frame = gif::Frame::from_indexed_pixels(w, h, indexed, None);
+        frame.delay = delay;
+        encoder.write_frame(&frame)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_gif, write_pbm, write_ppm};
+    use crate::Grid;
+
+    // the delay and the palette index of every pixel of a frame
+    type Frame = (u16, Vec<u8>);
+
+    // the size of a GIF, its palette and its frames
+    fn decode(gif: &[u8]) -> ((u16, u16), Vec<u8>, Vec<Frame>) {
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(gif).unwrap();
+        let size = (decoder.width(), decoder.height());
+        let palette = decoder.global_palette().unwrap().to_vec();
+
+        let mut frames = vec![];
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            frames.push((frame.delay, frame.buffer.to_vec()));
+        }
+        (size, palette, frames)
+    }
+
+    #[test]
+    fn still_images() {
+        // lit cells on a diagonal
+        let mut grid = Grid::new(2, 2, false);
+        grid[(0, 0)] = true;
+        grid[(1, 1)] = true;
+
+        let mut pbm = vec![];
+        write_pbm(&mut pbm, &grid, 1, |lit| !*lit).unwrap();
+        assert_eq!(String::from_utf8(pbm).unwrap(), "P1\n2 2\n0 1\n1 0\n");
+
+        let mut ppm = vec![];
+        write_ppm(&mut ppm, &grid, 2, |lit| {
+            if *lit {
+                (255, 0, 0)
+            } else {
+                (0, 0, 0)
+            }
+        })
+        .unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+        // the top left cell takes up two pixels of the first row
+        assert_eq!(ppm[11..20], [255, 0, 0, 255, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn animation() {
+        let small = Grid::new(1, 1, 1u8);
+        let mut big = Grid::new(2, 2, 1u8);
+        big[(0, 0)] = 2;
+        big[(1, 1)] = 2;
+
+        let mut gif = vec![];
+        let palette = [(0, 0, 0), (255, 255, 255), (255, 0, 0)];
+        write_gif(&mut gif, &[small, big], &palette, 2, 5, |i| *i).unwrap();
+
+        let (size, colors, frames) = decode(&gif);
+        assert_eq!(size, (4, 4));
+        assert_eq!(colors[..9], [0, 0, 0, 255, 255, 255, 255, 0, 0]);
+        assert_eq!(frames.len(), 2);
+        assert!(frames.iter().all(|(delay, _)| *delay == 5));
+        // the one cell frame is padded with the first color
+        assert_eq!(frames[0].1[..8], [1, 1, 0, 0, 1, 1, 0, 0]);
+        assert_eq!(frames[1].1[..8], [2, 2, 1, 1, 2, 2, 1, 1]);
+
+        // GIFs are at most 65535 pixels wide and high
+        let frames = [Grid::new(1, 1, 0u8)];
+        let err = write_gif(vec![], &frames, &palette, 1 << 16, 5, |i| *i).unwrap_err();
+        assert!(err.to_string().contains("too big"));
+    }
+}
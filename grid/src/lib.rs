@@ -0,0 +1,40 @@
+mod dense;
+mod direction;
+mod image;
+mod ocr;
+mod sparse;
+#[cfg(test)]
+mod testing;
+
+pub use dense::Grid;
+pub use direction::{Axes, Direction, Turn};
+pub use image::{write_gif, write_pbm, write_ppm, GifError, Rgb};
+pub use ocr::{read_letters, UNKNOWN};
+pub use sparse::SparseGrid;
+
+// (x, y), x grows to the right and y grows downwards like the rows of a map
+pub type Point = (i64, i64);
+
+// a cell that can be read from and drawn as a single character of a map
+pub trait Cell: Sized {
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+// up, right, down, left
+pub const NEIGHBOURS: [Point; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+// the four points sharing an edge with `p`
+pub fn neighbours((x, y): Point) -> impl Iterator<Item = Point> {
+    NEIGHBOURS.iter().map(move |(dx, dy)| (x + dx, y + dy))
+}
+
+// splits a character map into rows, ignoring blank lines around it but not
+// the spaces, which can be part of the map
+fn rows(text: &str) -> impl Iterator<Item = &str> {
+    text.trim_matches(|c| c == '\n' || c == '\r').lines()
+}
+
+fn parse_cell<T: Cell>(c: char, (x, y): Point) -> T {
+    T::from_char(c).unwrap_or_else(|| panic!("unexpected {:?} at ({}, {})", c, x, y))
+}
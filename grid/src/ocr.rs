@@ -0,0 +1,88 @@
+use crate::Grid;
+
+// the block letters the puzzles draw, six rows tall and mostly four columns
+// wide. only letters that have shown up in some puzzle are known.
+const GLYPHS: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+// what an unknown glyph reads as
+pub const UNKNOWN: char = '?';
+
+// reads the block letters in `grid`, `lit` tells which cells are drawn.
+// letters are split on the columns with nothing drawn in them.
+pub fn read_letters<T, F>(grid: &Grid<T>, lit: F) -> String
+where
+    F: Fn(&T) -> bool,
+{
+    let is_lit = |x: usize, y: usize| lit(&grid[(x as i64, y as i64)]);
+    let blank_column = |x: usize| (0..grid.height()).all(|y| !is_lit(x, y));
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < grid.width() {
+        if blank_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < grid.width() && !blank_column(x) {
+            x += 1;
+        }
+
+        let glyph: Vec<String> = (0..grid.height())
+            .map(|y| {
+                (start..x)
+                    .map(|x| if is_lit(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        letters.push(read_glyph(&glyph.join("\n")));
+    }
+    letters
+}
+
+fn read_glyph(glyph: &str) -> char {
+    GLYPHS
+        .iter()
+        .find(|(_, art)| *art == glyph)
+        .map_or(UNKNOWN, |(c, _)| *c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_letters;
+    use crate::Grid;
+
+    #[test]
+    fn letters() {
+        let art = [
+            " ##  #  # ###  #   #     ",
+            "#  # #  #  #   #   #     ",
+            "#  # ####  #    # #      ",
+            "#  # #  #  #     #       ",
+            "#  # #  #  #     #    ## ",
+            " ##  #  # ###    #    ## ",
+        ];
+        let grid: Grid<char> = Grid::parse(&art.join("\n"));
+        assert_eq!(read_letters(&grid, |c| *c == '#'), "OHIY?");
+    }
+}
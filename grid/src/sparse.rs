@@ -0,0 +1,150 @@
+use crate::{neighbours, parse_cell, rows, Cell, Point};
+use std::collections::HashMap;
+use std::fmt;
+
+// an unbounded grid that only stores the cells that were set. the bounds grow
+// to fit every cell that is set, points can be negative.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    min: Point,
+    max: Point,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            min: (0, 0),
+            max: (0, 0),
+        }
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    // sets the cell at `p`, returning what was there
+    pub fn insert(&mut self, p: Point, value: T) -> Option<T> {
+        if self.cells.is_empty() {
+            self.min = p;
+            self.max = p;
+        } else {
+            self.min = (self.min.0.min(p.0), self.min.1.min(p.1));
+            self.max = (self.max.0.max(p.0), self.max.1.max(p.1));
+        }
+        self.cells.insert(p, value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // top left and bottom right corners of the smallest rectangle holding
+    // every cell set so far
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        if self.cells.is_empty() {
+            None
+        } else {
+            Some((self.min, self.max))
+        }
+    }
+
+    // every set cell with its position, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(p, v)| (*p, v))
+    }
+
+    // the neighbours of `p` that are set
+    pub fn neighbours(&self, p: Point) -> impl Iterator<Item = (Point, &T)> {
+        neighbours(p).filter_map(move |n| self.get(n).map(|v| (n, v)))
+    }
+
+    // a grid of the cells in a character map, `blank` characters are left
+    // unset
+    pub fn parse(text: &str, blank: char) -> Self
+    where
+        T: Cell,
+    {
+        let mut grid = SparseGrid::new();
+        for (y, row) in rows(text).enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                let p = (x as i64, y as i64);
+                if c != blank {
+                    grid.insert(p, parse_cell(c, p));
+                }
+            }
+        }
+        grid
+    }
+
+    // draws the cells inside the bounds as a character map, `draw` gets None
+    // for the unset ones
+    pub fn render<F>(&self, draw: F) -> String
+    where
+        F: Fn(Option<&T>) -> char,
+    {
+        let mut s = String::new();
+        if let Some(((x0, y0), (x1, y1))) = self.bounds() {
+            for y in y0..=y1 {
+                for x in x0..=x1 {
+                    s.push(draw(self.get((x, y))));
+                }
+                s.push('\n');
+            }
+        }
+        s
+    }
+}
+
+// draws the grid with a space for every unset cell
+impl<T: Cell> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|c| c.map_or(' ', Cell::to_char)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SparseGrid;
+
+    #[test]
+    fn bounds_grow() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert((0, 0), 'a');
+        grid.insert((-2, 1), 'b');
+        grid.insert((1, -1), 'c');
+        assert_eq!(grid.bounds(), Some(((-2, -1), (1, 1))));
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.to_string(), "   c\n  a \nb   \n");
+    }
+
+    #[test]
+    fn parse_skips_blanks() {
+        let grid: SparseGrid<char> = SparseGrid::parse(" #\n#.", ' ');
+        assert_eq!(grid.len(), 3);
+        assert!(!grid.contains((0, 0)));
+        assert_eq!(grid.neighbours((1, 1)).count(), 2);
+        assert_eq!(grid.render(|c| *c.unwrap_or(&'?')), "?#\n#.\n");
+    }
+}
@@ -0,0 +1,13 @@
+// helpers shared by the tests of every module
+use crate::Cell;
+
+// plain characters, so tests can hold whatever they parse
+impl Cell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
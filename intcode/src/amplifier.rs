@@ -0,0 +1,205 @@
+use crate::{get_computer, IntCodeComputer, Signal};
+use std::sync::Mutex;
+use std::thread;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Topology {
+    // every amplifier runs once, passing its output to the next one
+    Serial,
+    // the last amplifier feeds back into the first till the machines halt
+    Feedback,
+}
+
+// all the ways of picking `n` distinct phases from `alphabet`, in lexicographic
+// order of their positions in the alphabet
+pub fn permutations(alphabet: &[i64], n: usize) -> Vec<Vec<i64>> {
+    fn helper(
+        alphabet: &[i64],
+        used: &mut Vec<bool>,
+        cur: &mut Vec<i64>,
+        n: usize,
+        out: &mut Vec<Vec<i64>>,
+    ) {
+        if cur.len() == n {
+            out.push(cur.clone());
+            return;
+        }
+        for i in 0..alphabet.len() {
+            if used[i] {
+                continue;
+            }
+            used[i] = true;
+            cur.push(alphabet[i]);
+            helper(alphabet, used, cur, n, out);
+            cur.pop();
+            used[i] = false;
+        }
+    }
+
+    let mut out = vec![];
+    if n <= alphabet.len() {
+        let mut used = vec![false; alphabet.len()];
+        helper(alphabet, &mut used, &mut vec![], n, &mut out);
+    }
+    out
+}
+
+// runs one amplifier per phase, each a clone of `base`, and returns the
+// signal coming out of the last one
+pub fn amplify(base: &IntCodeComputer, phases: &[i64], topology: Topology) -> i64 {
+    let mut amplifiers: Vec<IntCodeComputer> = phases
+        .iter()
+        .map(|phase| {
+            let mut amp = base.clone();
+            amp.feed_input(*phase);
+            amp
+        })
+        .collect();
+
+    let mut signal = 0;
+    'feedback: loop {
+        for amp in amplifiers.iter_mut() {
+            amp.feed_input(signal);
+            match amp.run() {
+                Signal::ProducedOutput => signal = amp.get_output().unwrap(),
+                Signal::Halt => break 'feedback,
+                _ => panic!("amplifier is waiting for more than one input"),
+            }
+        }
+
+        if topology == Topology::Serial {
+            break;
+        }
+    }
+
+    signal
+}
+
+// tries every phase setting for `amplifiers` amplifiers running `program` and
+// returns the one producing the highest signal, along with the signal
+pub fn best_phase_setting(
+    program: &[i64],
+    alphabet: &[i64],
+    amplifiers: usize,
+    topology: Topology,
+) -> Option<(Vec<i64>, i64)> {
+    let base = get_computer(program, vec![]);
+    let settings = permutations(alphabet, amplifiers);
+    if settings.is_empty() {
+        return None;
+    }
+
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = settings.len().div_ceil(threads);
+    let best: Mutex<Option<(usize, i64)>> = Mutex::new(None);
+
+    thread::scope(|s| {
+        for (chunk, phases) in settings.chunks(chunk_size).enumerate() {
+            let (base, best) = (&base, &best);
+            s.spawn(move || {
+                let mut local: Option<(usize, i64)> = None;
+                for (i, phase) in phases.iter().enumerate() {
+                    let signal = amplify(base, phase, topology);
+                    if local.is_none_or(|(_, s)| signal > s) {
+                        local = Some((chunk * chunk_size + i, signal));
+                    }
+                }
+
+                // ties go to the setting that comes first so the answer
+                // doesn't depend on which thread finishes first
+                let mut best = best.lock().unwrap();
+                if let Some((i, signal)) = local {
+                    if best.is_none_or(|(j, s)| signal > s || (signal == s && i < j)) {
+                        *best = Some((i, signal));
+                    }
+                }
+            });
+        }
+    });
+
+    let (i, signal) = best.into_inner().unwrap()?;
+    Some((settings[i].clone(), signal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permutations_of_alphabet() {
+        assert_eq!(permutations(&[0, 1, 2, 3, 4], 5).len(), 120);
+        assert_eq!(
+            permutations(&[1, 2, 3], 2),
+            vec![
+                vec![1, 2],
+                vec![1, 3],
+                vec![2, 1],
+                vec![2, 3],
+                vec![3, 1],
+                vec![3, 2]
+            ]
+        );
+        assert!(permutations(&[1, 2], 3).is_empty());
+    }
+
+    #[test]
+    fn serial_examples() {
+        let prog = vec![
+            3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+        ];
+        assert_eq!(
+            best_phase_setting(&prog, &[0, 1, 2, 3, 4], 5, Topology::Serial),
+            Some((vec![4, 3, 2, 1, 0], 43210))
+        );
+
+        let prog = vec![
+            3, 23, 3, 24, 1002, 24, 10, 24, 1002, 23, -1, 23, 101, 5, 23, 23, 1, 24, 23, 23, 4, 23,
+            99, 0, 0,
+        ];
+        assert_eq!(
+            best_phase_setting(&prog, &[0, 1, 2, 3, 4], 5, Topology::Serial),
+            Some((vec![0, 1, 2, 3, 4], 54321))
+        );
+
+        let prog = vec![
+            3, 31, 3, 32, 1002, 32, 10, 32, 1001, 31, -2, 31, 1007, 31, 0, 33, 1002, 33, 7, 33, 1,
+            33, 31, 31, 1, 32, 31, 31, 4, 31, 99, 0, 0, 0,
+        ];
+        assert_eq!(
+            best_phase_setting(&prog, &[0, 1, 2, 3, 4], 5, Topology::Serial),
+            Some((vec![1, 0, 4, 3, 2], 65210))
+        );
+    }
+
+    #[test]
+    fn feedback_examples() {
+        let prog = vec![
+            3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1,
+            28, 1005, 28, 6, 99, 0, 0, 5,
+        ];
+        assert_eq!(
+            best_phase_setting(&prog, &[5, 6, 7, 8, 9], 5, Topology::Feedback),
+            Some((vec![9, 8, 7, 6, 5], 139629729))
+        );
+
+        let prog = vec![
+            3, 52, 1001, 52, -5, 52, 3, 53, 1, 52, 56, 54, 1007, 54, 5, 55, 1005, 55, 26, 1001, 54,
+            -5, 54, 1105, 1, 12, 1, 53, 54, 53, 1008, 54, 0, 55, 1001, 55, 1, 55, 2, 53, 55, 53, 4,
+            53, 1001, 56, -1, 56, 1005, 56, 6, 99, 0, 0, 0, 0, 10,
+        ];
+        assert_eq!(
+            best_phase_setting(&prog, &[5, 6, 7, 8, 9], 5, Topology::Feedback),
+            Some((vec![9, 7, 8, 5, 6], 18216))
+        );
+    }
+
+    #[test]
+    fn amplify_with_fixed_setting() {
+        let prog = vec![
+            3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+        ];
+        let base = get_computer(&prog, vec![]);
+        assert_eq!(amplify(&base, &[4, 3, 2, 1, 0], Topology::Serial), 43210);
+        assert_eq!(amplify(&base, &[4, 3, 2], Topology::Serial), 432);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{IntCodeComputer, Signal};
+use futures::{Sink, SinkExt, Stream, StreamExt};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+// a busy machine hands control back to the executor after this many ticks so
+// it can't starve the other machines sharing the executor
+const TICKS_PER_YIELD: usize = 10_000;
+
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+impl IntCodeComputer {
+    // runs the machine, awaiting `input` whenever it runs out of queued input
+    // and sending every output to `output`.
+    //
+    // finishes with `Signal::Halt` once the program halts, or with
+    // `Signal::NeedsInput` if the input stream ends while the program still
+    // wants more.
+    pub async fn run_async<I, O>(&mut self, mut input: I, mut output: O) -> Result<Signal, O::Error>
+    where
+        I: Stream<Item = i64> + Unpin,
+        O: Sink<i64> + Unpin,
+    {
+        let mut ticks = 0;
+        loop {
+            ticks += 1;
+            if ticks % TICKS_PER_YIELD == 0 {
+                YieldNow(false).await;
+            }
+
+            match self.tick() {
+                Signal::None => {}
+                Signal::ProducedOutput => output.send(self.get_output().unwrap()).await?,
+                Signal::NeedsInput => match input.next().await {
+                    Some(value) => self.feed_input(value),
+                    None => return Ok(Signal::NeedsInput),
+                },
+                s => return Ok(s),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{get_computer, IntCodeComputer, Signal};
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::future::join_all;
+    use futures::{stream, StreamExt};
+
+    #[test]
+    fn runs_until_input_ends() {
+        // outputs every input doubled, forever
+        let prog = vec![3, 9, 1002, 9, 2, 9, 4, 9, 1105, 1, 0];
+        let mut comp = get_computer(&prog, vec![]);
+        let (tx, rx) = mpsc::unbounded();
+
+        let signal = block_on(comp.run_async(stream::iter(vec![1, 2, 3]), tx)).unwrap();
+        assert!(signal == Signal::NeedsInput);
+        assert_eq!(block_on(rx.collect::<Vec<i64>>()), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn hundreds_of_machines_on_one_executor() {
+        // adds one to its input
+        let prog = vec![3, 9, 1001, 9, 1, 9, 4, 9, 99, 0];
+        let n = 300;
+
+        let mut comps: Vec<IntCodeComputer> = (0..n).map(|_| get_computer(&prog, vec![])).collect();
+        let (mut txs, mut rxs): (Vec<_>, Vec<_>) = (0..=n).map(|_| mpsc::unbounded()).unzip();
+        txs[0].unbounded_send(0).unwrap();
+
+        // machine i reads from channel i and writes to channel i + 1, they are
+        // polled last to first so every machine has to wait for its input
+        let outputs = txs.split_off(1);
+        let runs = comps
+            .iter_mut()
+            .zip(rxs.iter_mut())
+            .zip(outputs)
+            .rev()
+            .map(|((comp, rx), tx)| comp.run_async(rx, tx));
+
+        for signal in block_on(join_all(runs)) {
+            assert!(signal.unwrap() == Signal::Halt);
+        }
+        assert_eq!(rxs[n].try_recv().unwrap(), n as i64);
+    }
+
+    #[test]
+    fn feedback_loop() {
+        let prog = vec![
+            3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1,
+            28, 1005, 28, 6, 99, 0, 0, 5,
+        ];
+        let phases = [9, 8, 7, 6, 5];
+
+        let mut comps: Vec<IntCodeComputer> = (0..5).map(|_| get_computer(&prog, vec![])).collect();
+        let (txs, mut rxs): (Vec<_>, Vec<_>) = (0..5).map(|_| mpsc::unbounded()).unzip();
+        for (tx, phase) in txs.iter().zip(phases.iter()) {
+            tx.unbounded_send(*phase).unwrap();
+        }
+        txs[0].unbounded_send(0).unwrap();
+
+        let runs = comps
+            .iter_mut()
+            .zip(rxs.iter_mut())
+            .enumerate()
+            .map(|(i, (comp, rx))| comp.run_async(rx, txs[(i + 1) % 5].clone()));
+        block_on(join_all(runs));
+
+        // the first amplifier halts before the last one sends its final signal
+        assert_eq!(rxs[0].try_recv().unwrap(), 139629729);
+    }
+}
@@ -0,0 +1,448 @@
+use crate::{IntCodeComputer, Signal};
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::io::{self, prelude::*, BufReader};
+use std::net::TcpListener;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::panic::{self, AssertUnwindSafe};
+
+// the most words a single `read` answers with
+const MAX_READ: i64 = 4096;
+
+// addresses past this are refused, memory grows to fit the highest one used
+const MAX_ADDR: i64 = 1 << 20;
+
+// how many instructions `continue` runs before giving up on the machine
+// stopping by itself
+const CONTINUE_TICKS: usize = 10_000_000;
+
+// Exposes a machine over a line oriented protocol, one command per line and
+// exactly one line in response, starting with `ok` or `err`.
+//
+//     step [n]               run n instructions (default 1)
+//     continue [n]           run till a breakpoint, halt, trap or missing
+//                            input, at most n instructions (default 10000000)
+//     break <addr>           set a breakpoint
+//     delete <addr>          remove a breakpoint
+//     breakpoints            list the breakpoints
+//     regs                   show ip and relative base
+//     set <ip|rb> <value>    change ip or relative base
+//     read <addr> [len]      read len words of memory (default 1, at most 4096)
+//     write <addr> <v>...    write words to memory starting at addr
+//
+// Addresses go up to 1048576, memory grows to fit the ones used.
+//
+//     input <v>...           queue input for the machine
+//     outputs                show and clear the outputs produced so far
+//     history <n>            keep the last n ticks so they can be undone
+//     back [n]               undo n instructions (default 1)
+//     rcontinue              undo till a breakpoint or the start of the history
+//     lastwrite <addr>       show the tick and ip of the last write to addr
+//     quit                   close the connection and stop serving
+//
+// `step` and `continue` answer with the signal the machine stopped on and
+// where, e.g. `ok breakpoint ip=12`, or `ok limit` when `continue` ran out of
+// instructions. A machine that crashes, say on a bad opcode after `set ip`
+// into data, answers `err` with what went wrong.
+pub struct DebugServer {
+    comp: IntCodeComputer,
+    breakpoints: BTreeSet<usize>,
+    outputs: Vec<i64>,
+}
+
+fn signal_name(signal: &Signal) -> &'static str {
+    match signal {
+        Signal::NeedsInput => "input",
+        Signal::ProducedOutput => "output",
+        Signal::Halt => "halt",
+        Signal::Trap => "trap",
+        Signal::None => "none",
+    }
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    match panic.downcast_ref::<&str>() {
+        Some(s) => s.to_string(),
+        None => panic
+            .downcast_ref::<String>()
+            .cloned()
+            .unwrap_or_else(|| "the machine crashed".to_string()),
+    }
+}
+
+fn parse_args(args: &[&str]) -> Result<Vec<i64>, String> {
+    args.iter()
+        .map(|x| x.parse::<i64>().map_err(|_| format!("bad number {}", x)))
+        .collect()
+}
+
+impl DebugServer {
+    pub fn new(comp: IntCodeComputer) -> Self {
+        DebugServer {
+            comp,
+            breakpoints: BTreeSet::new(),
+            outputs: vec![],
+        }
+    }
+
+    pub fn computer(&self) -> &IntCodeComputer {
+        &self.comp
+    }
+
+    pub fn into_computer(self) -> IntCodeComputer {
+        self.comp
+    }
+
+    fn tick(&mut self) -> Signal {
+        let signal = self.comp.tick();
+        if signal == Signal::ProducedOutput {
+            self.outputs.push(self.comp.get_output().unwrap());
+        }
+        signal
+    }
+
+    fn stopped(&self, reason: &str) -> String {
+        format!("ok {} ip={}", reason, self.comp.ip())
+    }
+
+    fn step(&mut self, n: usize) -> String {
+        let mut signal = Signal::None;
+        for _ in 0..n {
+            signal = self.tick();
+            if let Signal::Halt | Signal::NeedsInput | Signal::Trap = signal {
+                break;
+            }
+        }
+        self.stopped(signal_name(&signal))
+    }
+
+    fn cont(&mut self, max_ticks: usize) -> String {
+        for tick in 0..max_ticks {
+            // always move off the current instruction, else continuing from a
+            // breakpoint would stop right where it started
+            if tick > 0 && self.breakpoints.contains(&self.comp.ip()) {
+                return self.stopped("breakpoint");
+            }
+
+            match self.tick() {
+                s @ Signal::Halt | s @ Signal::NeedsInput | s @ Signal::Trap => {
+                    return self.stopped(signal_name(&s))
+                }
+                _ => {}
+            }
+        }
+        self.stopped("limit")
+    }
+
+    fn back(&mut self, n: usize) -> String {
+        for _ in 0..n {
+            if !self.comp.step_back() {
+                return self.stopped("start");
+            }
+        }
+        self.stopped("none")
+    }
+
+    fn reverse_cont(&mut self) -> String {
+        let breakpoints: Vec<usize> = self.breakpoints.iter().cloned().collect();
+        if self.comp.reverse_continue(&breakpoints) {
+            self.stopped("breakpoint")
+        } else {
+            self.stopped("start")
+        }
+    }
+
+    fn read(&mut self, addr: i64, len: i64) -> String {
+        let words = (addr..addr + len)
+            .map(|i| self.comp.get_value_at_pos(i).to_string())
+            .collect::<Vec<String>>();
+        format!("ok {}", words.join(" "))
+    }
+
+    fn list(values: impl Iterator<Item = String>) -> String {
+        values.fold("ok".to_string(), |acc, x| acc + " " + &x)
+    }
+
+    // returns the response to a single command, None if the client wants out
+    // which is answered with `ok` by `serve`
+    pub fn handle(&mut self, line: &str) -> Option<String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let (cmd, args) = match words.split_first() {
+            Some((cmd, args)) => (*cmd, args),
+            None => return Some("err empty command".to_string()),
+        };
+        let args = match parse_args(args) {
+            Ok(args) => args,
+            // `set` is the only command taking a non numeric argument
+            Err(_) if cmd == "set" && args.len() == 2 => match parse_args(&args[1..]) {
+                Ok(v) => return Some(self.set(args[0], v[0])),
+                Err(e) => return Some(format!("err {}", e)),
+            },
+            Err(e) => return Some(format!("err {}", e)),
+        };
+
+        let run = panic::catch_unwind(AssertUnwindSafe(|| self.run_command(cmd, &args, line)));
+        match run {
+            Ok(response) => response,
+            Err(panic) => Some(format!("err {}", panic_message(panic.as_ref()))),
+        }
+    }
+
+    fn run_command(&mut self, cmd: &str, args: &[i64], line: &str) -> Option<String> {
+        let response = match (cmd, args) {
+            ("step", []) => self.step(1),
+            ("step", [n]) if *n > 0 => self.step(*n as usize),
+            ("continue", []) => self.cont(CONTINUE_TICKS),
+            ("continue", [n]) if *n > 0 => self.cont(*n as usize),
+            ("break", [addr]) if *addr >= 0 => {
+                self.breakpoints.insert(*addr as usize);
+                "ok".to_string()
+            }
+            ("delete", [addr]) if *addr >= 0 => {
+                if self.breakpoints.remove(&(*addr as usize)) {
+                    "ok".to_string()
+                } else {
+                    format!("err no breakpoint at {}", addr)
+                }
+            }
+            ("breakpoints", []) => Self::list(self.breakpoints.iter().map(|x| x.to_string())),
+            ("regs", []) => format!("ok ip={} rb={}", self.comp.ip(), self.comp.relative_base()),
+            ("read", [addr, ..]) if *addr > MAX_ADDR => {
+                format!("err addresses go up to {}", MAX_ADDR)
+            }
+            ("write", [addr, values @ ..])
+                if addr.saturating_add(values.len() as i64) > MAX_ADDR + 1 =>
+            {
+                format!("err addresses go up to {}", MAX_ADDR)
+            }
+            ("read", [addr]) if *addr >= 0 => self.read(*addr, 1),
+            ("read", [addr, len]) if *addr >= 0 && *len >= 0 && *len <= MAX_READ => {
+                self.read(*addr, (*len).min(MAX_ADDR + 1 - addr))
+            }
+            ("read", [_, len]) if *len > MAX_READ => {
+                format!("err can not read more than {} words at once", MAX_READ)
+            }
+            ("write", [addr, values @ ..]) if *addr >= 0 && !values.is_empty() => {
+                for (i, v) in values.iter().enumerate() {
+                    self.comp.store_value_at_pos(addr + i as i64, *v);
+                }
+                "ok".to_string()
+            }
+            ("input", values) if !values.is_empty() => {
+                values.iter().for_each(|v| self.comp.feed_input(*v));
+                "ok".to_string()
+            }
+            ("history", [n]) if *n >= 0 => {
+                self.comp.enable_history(*n as usize);
+                "ok".to_string()
+            }
+            ("back", []) => self.back(1),
+            ("back", [n]) if *n > 0 => self.back(*n as usize),
+            ("rcontinue", []) => self.reverse_cont(),
+            ("lastwrite", [addr]) if *addr >= 0 => match self.comp.last_write(*addr as usize) {
+                Some(w) => format!("ok tick={} ip={} old={}", w.tick, w.ip, w.old),
+                None => format!("err no write to {} in the history", addr),
+            },
+            ("outputs", []) => Self::list(self.outputs.drain(..).map(|x| x.to_string())),
+            ("quit", []) => return None,
+            _ => format!("err bad command: {}", line.trim()),
+        };
+        Some(response)
+    }
+
+    fn set(&mut self, reg: &str, value: i64) -> String {
+        match reg {
+            "ip" if value >= 0 => self.comp.set_ip(value as usize),
+            "rb" => self.comp.set_relative_base(value),
+            _ => return format!("err can not set {} to {}", reg, value),
+        }
+        "ok".to_string()
+    }
+
+    // serves a single client, returns once it quits or goes away
+    pub fn serve<R: BufRead, W: Write>(&mut self, reader: R, mut writer: W) -> io::Result<()> {
+        for line in reader.lines() {
+            match self.handle(&line?) {
+                Some(response) => {
+                    writeln!(writer, "{}", response)?;
+                    writer.flush()?;
+                }
+                None => {
+                    writeln!(writer, "ok")?;
+                    writer.flush()?;
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn serve_tcp(&mut self, listener: &TcpListener) -> io::Result<()> {
+        let (stream, _) = listener.accept()?;
+        stream.set_nodelay(true)?;
+        self.serve(BufReader::new(stream.try_clone()?), stream)
+    }
+
+    #[cfg(unix)]
+    pub fn serve_unix(&mut self, listener: &UnixListener) -> io::Result<()> {
+        let (stream, _) = listener.accept()?;
+        self.serve(BufReader::new(stream.try_clone()?), stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_computer;
+    use std::net::TcpStream;
+    use std::thread;
+
+    struct Client {
+        reader: BufReader<TcpStream>,
+        writer: TcpStream,
+    }
+
+    impl Client {
+        fn send(&mut self, cmd: &str) -> String {
+            writeln!(self.writer, "{}", cmd).unwrap();
+            let mut line = String::new();
+            self.reader.read_line(&mut line).unwrap();
+            line.trim_end().to_string()
+        }
+    }
+
+    fn start(prog: Vec<i64>) -> (Client, thread::JoinHandle<IntCodeComputer>) {
+        start_with(get_computer(&prog, vec![]))
+    }
+
+    fn start_with(comp: IntCodeComputer) -> (Client, thread::JoinHandle<IntCodeComputer>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = thread::spawn(move || {
+            let mut server = DebugServer::new(comp);
+            server.serve_tcp(&listener).unwrap();
+            server.into_computer()
+        });
+
+        let stream = TcpStream::connect(addr).unwrap();
+        stream.set_nodelay(true).unwrap();
+        let client = Client {
+            reader: BufReader::new(stream.try_clone().unwrap()),
+            writer: stream,
+        };
+        (client, handle)
+    }
+
+    #[test]
+    fn step_and_inspect() {
+        // adds the input to itself and outputs it
+        let (mut c, handle) = start(vec![3, 9, 1, 9, 9, 9, 4, 9, 99, 0]);
+
+        assert_eq!(c.send("regs"), "ok ip=0 rb=0");
+        assert_eq!(c.send("step"), "ok input ip=0");
+        assert_eq!(c.send("input 21"), "ok");
+        assert_eq!(c.send("step 2"), "ok none ip=6");
+        assert_eq!(c.send("read 9"), "ok 42");
+        assert_eq!(c.send("read 6 3"), "ok 4 9 99");
+        assert_eq!(c.send("step 5"), "ok halt ip=9");
+        assert_eq!(c.send("outputs"), "ok 42");
+        assert_eq!(c.send("outputs"), "ok");
+        assert_eq!(c.send("quit"), "ok");
+
+        assert_eq!(handle.join().unwrap().memory()[9], 42);
+    }
+
+    #[test]
+    fn breakpoints_and_registers() {
+        // counts down from 3, printing each value
+        let prog = vec![4, 12, 1001, 12, -1, 12, 1005, 12, 0, 99, 0, 0, 3];
+        let (mut c, handle) = start(prog);
+
+        assert_eq!(c.send("break 6"), "ok");
+        assert_eq!(c.send("break 9"), "ok");
+        assert_eq!(c.send("breakpoints"), "ok 6 9");
+        assert_eq!(c.send("continue"), "ok breakpoint ip=6");
+        assert_eq!(c.send("continue"), "ok breakpoint ip=6");
+        assert_eq!(c.send("outputs"), "ok 3 2");
+        assert_eq!(c.send("delete 6"), "ok");
+        assert_eq!(c.send("delete 6"), "err no breakpoint at 6");
+        assert_eq!(c.send("continue"), "ok breakpoint ip=9");
+        assert_eq!(c.send("outputs"), "ok 1");
+
+        assert_eq!(c.send("set rb 7"), "ok");
+        assert_eq!(c.send("write 12 5"), "ok");
+        assert_eq!(c.send("set ip 0"), "ok");
+        assert_eq!(c.send("regs"), "ok ip=0 rb=7");
+        assert_eq!(c.send("step"), "ok output ip=2");
+        assert_eq!(c.send("outputs"), "ok 5");
+
+        assert_eq!(c.send("jump 3"), "err bad command: jump 3");
+        assert_eq!(c.send("read x"), "err bad number x");
+        assert_eq!(c.send("set sp 1"), "err can not set sp to 1");
+        c.send("quit");
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn reverse_execution() {
+        let prog = vec![4, 12, 1001, 12, -1, 12, 1005, 12, 0, 99, 0, 0, 3];
+        let (mut c, handle) = start(prog);
+
+        assert_eq!(c.send("history 100"), "ok");
+        assert_eq!(c.send("break 2"), "ok");
+        assert_eq!(c.send("continue"), "ok breakpoint ip=2");
+        assert_eq!(c.send("continue"), "ok breakpoint ip=2");
+        assert_eq!(c.send("read 12"), "ok 2");
+        assert_eq!(c.send("lastwrite 12"), "ok tick=1 ip=2 old=3");
+        assert_eq!(c.send("rcontinue"), "ok breakpoint ip=2");
+        assert_eq!(c.send("read 12"), "ok 3");
+        assert_eq!(c.send("back 2"), "ok start ip=0");
+        assert_eq!(c.send("lastwrite 12"), "err no write to 12 in the history");
+        c.send("quit");
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn survives_crashes() {
+        let (mut c, handle) = start(vec![1101, 2, 3, 5, 99, 0]);
+
+        // the data after the halt is no instruction
+        assert_eq!(c.send("set ip 5"), "ok");
+        assert_eq!(c.send("step"), "err unknown instruction");
+        assert_eq!(c.send("set ip 0"), "ok");
+        assert_eq!(c.send("write 4 77"), "ok");
+        assert_eq!(c.send("continue"), "err unknown instruction");
+
+        assert_eq!(
+            c.send("read 0 4097"),
+            "err can not read more than 4096 words at once"
+        );
+        assert_eq!(c.send("read 0 2"), "ok 1101 2");
+        assert_eq!(
+            c.send("write 999999999999 1"),
+            "err addresses go up to 1048576"
+        );
+        assert_eq!(c.send("read 1048577"), "err addresses go up to 1048576");
+        assert_eq!(
+            c.send("write 1048576 1 2"),
+            "err addresses go up to 1048576"
+        );
+        assert_eq!(c.send("quit"), "ok");
+        assert_eq!(handle.join().unwrap().memory()[5], 5);
+    }
+
+    #[test]
+    fn stops_on_traps_and_endless_loops() {
+        // overwrites its own first instruction, then jumps to itself forever
+        let mut comp = get_computer(&[1101, 0, 0, 0, 1105, 1, 4], vec![]);
+        comp.track_self_modification(true);
+        let (mut c, handle) = start_with(comp);
+
+        assert_eq!(c.send("step 5"), "ok trap ip=4");
+        assert_eq!(c.send("continue 100"), "ok limit ip=4");
+        assert_eq!(c.send("continue 0"), "err bad command: continue 0");
+        c.send("quit");
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{IntCodeComputer, Signal};
+use std::collections::VecDeque;
+
+// everything a single tick changed, enough to put the machine back to where
+// it was before the tick
+#[derive(Clone)]
+struct TickRecord {
+    tick: u64,
+    ip: usize,
+    relative_base: i64,
+    output: Option<i64>,
+    writes: Vec<(usize, i64)>,
+    input: Option<i64>,
+}
+
+// the last write to an address that is still in the history
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LastWrite {
+    // number of ticks executed before the writing instruction
+    pub tick: u64,
+    // address of the writing instruction
+    pub ip: usize,
+    pub old: i64,
+}
+
+#[derive(Clone)]
+pub struct History {
+    log: VecDeque<TickRecord>,
+    limit: usize,
+    ticks: u64,
+    current: Option<TickRecord>,
+}
+
+impl History {
+    pub fn new(limit: usize) -> Self {
+        History {
+            log: VecDeque::with_capacity(limit.min(1 << 16)),
+            limit,
+            ticks: 0,
+            current: None,
+        }
+    }
+
+    pub fn begin(&mut self, ip: usize, relative_base: i64, output: Option<i64>) {
+        self.current = Some(TickRecord {
+            tick: self.ticks,
+            ip,
+            relative_base,
+            output,
+            writes: vec![],
+            input: None,
+        });
+    }
+
+    pub fn record_write(&mut self, addr: usize, old: i64) {
+        if let Some(rec) = self.current.as_mut() {
+            rec.writes.push((addr, old));
+        }
+    }
+
+    pub fn record_input(&mut self, input: i64) {
+        if let Some(rec) = self.current.as_mut() {
+            rec.input = Some(input);
+        }
+    }
+
+    pub fn commit(&mut self) {
+        if let Some(rec) = self.current.take() {
+            if self.log.len() == self.limit {
+                self.log.pop_front();
+            }
+            if self.limit > 0 {
+                self.log.push_back(rec);
+            }
+            self.ticks += 1;
+        }
+    }
+
+    // forgets the tick in progress, for ticks that didn't change anything
+    pub fn discard(&mut self) {
+        self.current = None;
+    }
+
+    fn pop(&mut self) -> Option<TickRecord> {
+        let rec = self.log.pop_back()?;
+        self.ticks = rec.tick;
+        Some(rec)
+    }
+}
+
+impl IntCodeComputer {
+    // keep an undo log of the last `limit` ticks so they can be stepped back
+    pub fn enable_history(&mut self, limit: usize) -> &mut Self {
+        self.history = Some(History::new(limit));
+        self
+    }
+
+    // number of ticks executed since the history was enabled
+    pub fn ticks(&self) -> u64 {
+        self.history.as_ref().map_or(0, |h| h.ticks)
+    }
+
+    // number of ticks that can still be undone
+    pub fn history_len(&self) -> usize {
+        self.history.as_ref().map_or(0, |h| h.log.len())
+    }
+
+    // undoes the last tick, returns false if there is nothing left to undo.
+    // the self modification tracking is not rewound, so writes over code
+    // that were undone are still reported and the code they ran stays
+    // marked as executed.
+    pub fn step_back(&mut self) -> bool {
+        let rec = match self.history.as_mut().and_then(|h| h.pop()) {
+            Some(rec) => rec,
+            None => return false,
+        };
+
+        for (addr, old) in rec.writes.into_iter().rev() {
+            self.memory[addr] = old;
+        }
+        if let Some(input) = rec.input {
+            self.input.insert(0, input);
+        }
+        self.ip = rec.ip;
+        self.relative_base_offset = rec.relative_base;
+        self.output = rec.output;
+        true
+    }
+
+    // steps back till the machine is about to execute one of the breakpoints,
+    // returns false if the history ran out first
+    pub fn reverse_continue(&mut self, breakpoints: &[usize]) -> bool {
+        while self.step_back() {
+            if breakpoints.contains(&self.ip) {
+                return true;
+            }
+        }
+        false
+    }
+
+    // runs forward like `run` but also stops before any of the breakpoints
+    pub fn continue_to(&mut self, breakpoints: &[usize]) -> Signal {
+        loop {
+            match self.tick() {
+                Signal::None if breakpoints.contains(&self.ip) => return Signal::None,
+                Signal::None => {}
+                s => return s,
+            }
+        }
+    }
+
+    // who last wrote to `addr`, as far back as the history goes
+    pub fn last_write(&self, addr: usize) -> Option<LastWrite> {
+        let history = self.history.as_ref()?;
+        history.log.iter().rev().find_map(|rec| {
+            rec.writes
+                .iter()
+                .rev()
+                .find(|(a, _)| *a == addr)
+                .map(|(_, old)| LastWrite {
+                    tick: rec.tick,
+                    ip: rec.ip,
+                    old: *old,
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{get_computer, Signal};
+
+    // reads two numbers and outputs their sum, twice
+    const PROG: [i64; 13] = [3, 11, 3, 12, 1, 11, 12, 11, 4, 11, 99, 0, 0];
+
+    #[test]
+    fn step_back_restores_state() {
+        let mut comp = get_computer(&PROG, vec![20, 22]);
+        comp.enable_history(100);
+
+        assert!(comp.run() == Signal::ProducedOutput);
+        assert_eq!(comp.get_output(), Some(42));
+        assert_eq!(comp.ticks(), 4);
+        assert_eq!(comp.memory()[11], 42);
+
+        assert!(comp.step_back()); // output
+        assert!(comp.step_back()); // add
+        assert_eq!(comp.memory()[11], 20);
+        assert_eq!(comp.ip(), 4);
+
+        assert!(comp.step_back()); // second input
+        assert!(comp.step_back()); // first input
+        assert!(!comp.step_back());
+        assert_eq!(comp.ip(), 0);
+        assert_eq!(comp.ticks(), 0);
+        assert_eq!(&comp.memory()[11..], &[0, 0]);
+
+        // the inputs went back into the queue so the run can be replayed
+        assert!(comp.run() == Signal::ProducedOutput);
+        assert_eq!(comp.get_output(), Some(42));
+    }
+
+    #[test]
+    fn reverse_continue_and_last_write() {
+        let mut comp = get_computer(&PROG, vec![1, 2]);
+        comp.enable_history(100);
+        comp.run_till_signal(Signal::Halt);
+
+        let w = comp.last_write(11).unwrap();
+        assert_eq!((w.tick, w.ip, w.old), (2, 4, 1));
+        assert_eq!(comp.last_write(12).unwrap().ip, 2);
+        assert_eq!(comp.last_write(5), None);
+
+        assert!(comp.reverse_continue(&[2]));
+        assert_eq!(comp.ip(), 2);
+        assert!(!comp.reverse_continue(&[2]));
+        assert!(comp.continue_to(&[8]) == Signal::None);
+        assert_eq!(comp.ip(), 8);
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut comp = get_computer(&PROG, vec![1, 2]);
+        comp.enable_history(2);
+        comp.run_till_signal(Signal::Halt);
+
+        assert_eq!(comp.history_len(), 2);
+        assert!(comp.step_back());
+        assert!(comp.step_back());
+        assert!(!comp.step_back());
+        assert_eq!(comp.ip(), 8);
+        assert_eq!(comp.ticks(), 3);
+    }
+
+    #[test]
+    fn waiting_for_input_is_not_a_tick() {
+        let mut comp = get_computer(&PROG, vec![20]);
+        comp.enable_history(100);
+
+        assert!(comp.run() == Signal::NeedsInput);
+        assert!(comp.run() == Signal::NeedsInput);
+        assert_eq!((comp.ticks(), comp.history_len()), (1, 1));
+
+        // undoing goes straight back over the first input
+        assert!(comp.step_back());
+        assert_eq!(comp.ip(), 0);
+        assert!(!comp.step_back());
+    }
+}
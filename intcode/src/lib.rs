@@ -1,3 +1,19 @@
+mod amplifier;
+mod asynchronous;
+mod debugger;
+mod history;
+mod search;
+mod smc;
+
+pub use amplifier::{amplify, best_phase_setting, permutations, Topology};
+pub use debugger::DebugServer;
+pub use history::LastWrite;
+pub use search::Search;
+pub use smc::SelfModification;
+
+use history::History;
+use smc::SmcTracker;
+
 pub enum Parameter {
     Position(i64),
     Immediate(i64),
@@ -22,15 +38,28 @@ pub enum Signal {
     NeedsInput,
     ProducedOutput,
     Halt,
+    // an instruction wrote over code that has already run, only raised when
+    // self modification tracking is on with trapping
+    Trap,
     None,
 }
 
+#[derive(Clone)]
 pub struct IntCodeComputer {
     memory: Vec<i64>,
     input: Vec<i64>,
     output: Option<i64>,
     relative_base_offset: i64,
     ip: usize,
+    inst_ip: usize,
+    smc: Option<SmcTracker>,
+    history: Option<History>,
+}
+
+impl Default for IntCodeComputer {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl IntCodeComputer {
@@ -41,6 +70,9 @@ impl IntCodeComputer {
             output: None,
             relative_base_offset: 0,
             ip: 0,
+            inst_ip: 0,
+            smc: None,
+            history: None,
         }
     }
 
@@ -66,6 +98,26 @@ impl IntCodeComputer {
         }
     }
 
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base_offset
+    }
+
+    pub fn set_ip(&mut self, ip: usize) {
+        self.ip = ip;
+    }
+
+    pub fn set_relative_base(&mut self, relative_base: i64) {
+        self.relative_base_offset = relative_base;
+    }
+
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
     pub fn get_value_at_pos(&mut self, i: i64) -> i64 {
         if (i as usize) >= self.memory.len() {
             return 0;
@@ -73,12 +125,37 @@ impl IntCodeComputer {
         self.memory[i as usize]
     }
 
+    // keep track of the addresses executed as code and report writes to them,
+    // with `trap` set `tick` returns `Signal::Trap` after every such write
+    pub fn track_self_modification(&mut self, trap: bool) -> &mut Self {
+        self.smc = Some(SmcTracker::new(trap));
+        self
+    }
+
+    pub fn self_modifications(&self) -> &[SelfModification] {
+        self.smc.as_ref().map_or(&[], |smc| smc.report())
+    }
+
     pub fn store_value_at_pos(&mut self, i: i64, value: i64) {
+        self.write(None, i, value);
+    }
+
+    fn write(&mut self, site: Option<usize>, i: i64, value: i64) {
+        let old = self.memory.get(i as usize).copied().unwrap_or(0);
+        if let Some(smc) = self.smc.as_mut() {
+            smc.check_write(site, i as usize, old, value);
+        }
+        if let Some(history) = self.history.as_mut() {
+            history.record_write(i as usize, old);
+        }
         self.try_resize_memory(i);
         self.memory[i as usize] = value;
     }
 
     fn fetch_word(&mut self) -> i64 {
+        if let Some(smc) = self.smc.as_mut() {
+            smc.mark_executed(self.ip);
+        }
         let word = self.get_value_at_pos(self.ip as i64);
         self.ip += 1;
         word
@@ -123,10 +200,10 @@ impl IntCodeComputer {
     fn store_val(&mut self, param: Parameter, val: i64) {
         match param {
             Parameter::Position(out) => {
-                self.store_value_at_pos(out, val);
+                self.write(Some(self.inst_ip), out, val);
             }
             Parameter::Relative(out) => {
-                self.store_value_at_pos(self.relative_base_offset + out, val);
+                self.write(Some(self.inst_ip), self.relative_base_offset + out, val);
             }
             _ => panic!("can not store to parameter in immediate mode"),
         }
@@ -171,19 +248,39 @@ impl IntCodeComputer {
     }
 
     pub fn tick(&mut self) -> Signal {
+        self.inst_ip = self.ip;
+        if let Some(history) = self.history.as_mut() {
+            history.begin(self.ip, self.relative_base_offset, self.output);
+        }
+        let signal = self.execute_instruction();
+        if let Some(history) = self.history.as_mut() {
+            // waiting for input leaves the machine as it was
+            if signal == Signal::NeedsInput {
+                history.discard();
+            } else {
+                history.commit();
+            }
+        }
+        if self.smc.as_mut().is_some_and(|smc| smc.take_trap()) {
+            return Signal::Trap;
+        }
+        signal
+    }
+
+    fn execute_instruction(&mut self) -> Signal {
         let inst = self.fetch_instruction();
         match inst {
             Instruction::Add((param1, param2, param3)) => {
                 let op1 = self.unwrap_value(param1);
                 let op2 = self.unwrap_value(param2);
                 self.store_val(param3, op1 + op2);
-                return Signal::None;
+                Signal::None
             }
             Instruction::Mul((param1, param2, param3)) => {
                 let op1 = self.unwrap_value(param1);
                 let op2 = self.unwrap_value(param2);
                 self.store_val(param3, op1 * op2);
-                return Signal::None;
+                Signal::None
             }
             Instruction::Input(param) => {
                 if self.input.is_empty() {
@@ -191,42 +288,45 @@ impl IntCodeComputer {
                     return Signal::NeedsInput;
                 }
                 let inp = self.input.remove(0);
+                if let Some(history) = self.history.as_mut() {
+                    history.record_input(inp);
+                }
                 self.store_val(param, inp);
-                return Signal::None;
+                Signal::None
             }
             Instruction::Output(param) => {
                 self.emit_output(param);
-                return Signal::ProducedOutput;
+                Signal::ProducedOutput
             }
             Instruction::JumpIfTrue((param1, param2)) => {
                 if self.unwrap_value(param1) != 0 {
                     self.jump(param2);
                 }
-                return Signal::None;
+                Signal::None
             }
             Instruction::JumpIfFalse((param1, param2)) => {
                 if self.unwrap_value(param1) == 0 {
                     self.jump(param2);
                 }
-                return Signal::None;
+                Signal::None
             }
             Instruction::LessThan((param1, param2, param3)) => {
                 let op1 = self.unwrap_value(param1);
                 let op2 = self.unwrap_value(param2);
                 self.store_val(param3, if op1 < op2 { 1 } else { 0 });
-                return Signal::None;
+                Signal::None
             }
             Instruction::Equals((param1, param2, param3)) => {
                 let op1 = self.unwrap_value(param1);
                 let op2 = self.unwrap_value(param2);
                 self.store_val(param3, if op1 == op2 { 1 } else { 0 });
-                return Signal::None;
+                Signal::None
             }
             Instruction::RelativeBaseOffset(offset) => {
                 self.relative_base_offset += self.unwrap_value(offset);
-                return Signal::None;
+                Signal::None
             }
-            Instruction::Halt => return Signal::Halt,
+            Instruction::Halt => Signal::Halt,
         }
     }
 
@@ -243,8 +343,47 @@ impl IntCodeComputer {
     }
 }
 
-pub fn get_computer(mem: &Vec<i64>, input: Vec<i64>) -> IntCodeComputer {
+// panics on anything that isn't a comma separated list of numbers, use
+// `try_parse_program` for programs that didn't come with the puzzle
+pub fn parse_program(s: &str) -> Vec<i64> {
+    try_parse_program(s).unwrap_or_else(|e| panic!("{}", e))
+}
+
+// fails on the first value that isn't a number, naming it and its index
+pub fn try_parse_program(s: &str) -> Result<Vec<i64>, String> {
+    if s.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    s.trim()
+        .split(',')
+        .enumerate()
+        .map(|(i, x)| {
+            x.trim()
+                .parse::<i64>()
+                .map_err(|_| format!("bad value {:?} at index {} of the program", x.trim(), i))
+        })
+        .collect()
+}
+
+pub fn get_computer(mem: &[i64], input: Vec<i64>) -> IntCodeComputer {
     let mut computer = IntCodeComputer::new();
-    computer.load_memory(mem.clone()).set_input(input);
+    computer.load_memory(mem.to_vec()).set_input(input);
     computer
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_program, try_parse_program};
+
+    #[test]
+    fn parses_programs() {
+        assert_eq!(parse_program("1,0, -3,99\n"), vec![1, 0, -3, 99]);
+        assert_eq!(try_parse_program(" \n"), Ok(vec![]));
+        assert_eq!(
+            try_parse_program("1,0,x3,99"),
+            Err("bad value \"x3\" at index 2 of the program".to_string())
+        );
+        assert!(try_parse_program("1,,99").is_err());
+    }
+}
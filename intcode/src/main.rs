@@ -0,0 +1,279 @@
+use intcode::{get_computer, try_parse_program, DebugServer, IntCodeComputer, Signal};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, prelude::*};
+use std::net::TcpListener;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+
+const EXIT_HALT: i32 = 0;
+const EXIT_ERROR: i32 = 1;
+const EXIT_NEEDS_INPUT: i32 = 2;
+
+const USAGE: &str = "usage: intcode [options] <program>
+
+options:
+    -i, --input <value>        feed input (comma separated numbers, or a line of text in ascii mode)
+    -f, --input-file <path>    feed the contents of a file as input
+    -s, --stdin                feed stdin as input
+    -a, --ascii                read and write ascii instead of numbers
+    -p, --patch <addr>=<val>   store <val> at <addr> before running
+    -m, --dump-memory          print the final memory
+    -S, --state                print the state the machine stopped in
+    -c, --self-modification    report writes to addresses that have already run as code
+    -d, --serve <addr>         run under the debug server on a tcp address, or unix:<path>
+    -h, --help                 print this message
+
+exit codes:
+    0    the program halted
+    1    error
+    2    the program is waiting for input";
+
+#[derive(Default)]
+struct Options {
+    program: Option<String>,
+    inputs: Vec<String>,
+    ascii: bool,
+    patches: Vec<(i64, i64)>,
+    dump_memory: bool,
+    print_state: bool,
+    self_modification: bool,
+    serve: Option<String>,
+}
+
+fn parse_patch(s: &str) -> Result<(i64, i64), Box<dyn Error>> {
+    let mut split = s.splitn(2, '=');
+    let addr = split.next().unwrap().trim().parse::<i64>()?;
+    if addr < 0 {
+        return Err(format!("patch '{}' has a negative address", s).into());
+    }
+    let value = split
+        .next()
+        .ok_or_else(|| format!("patch '{}' should be of the form <addr>=<val>", s))?
+        .trim()
+        .parse::<i64>()?;
+    Ok((addr, value))
+}
+
+fn parse_args(args: &[String]) -> Result<Options, Box<dyn Error>> {
+    let mut opts = Options::default();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("missing value for {}", arg))
+        };
+
+        match arg.as_str() {
+            "-i" | "--input" => {
+                let mut inp = value()?;
+                inp.push('\n');
+                opts.inputs.push(inp);
+            }
+            "-f" | "--input-file" => opts.inputs.push(fs::read_to_string(value()?)?),
+            "-s" | "--stdin" => {
+                let mut s = String::new();
+                io::stdin().read_to_string(&mut s)?;
+                opts.inputs.push(s);
+            }
+            "-a" | "--ascii" => opts.ascii = true,
+            "-p" | "--patch" => opts.patches.push(parse_patch(&value()?)?),
+            "-m" | "--dump-memory" => opts.dump_memory = true,
+            "-S" | "--state" => opts.print_state = true,
+            "-c" | "--self-modification" => opts.self_modification = true,
+            "-d" | "--serve" => opts.serve = Some(value()?),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(EXIT_HALT);
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg).into()),
+            _ if opts.program.is_none() => opts.program = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument {}", arg).into()),
+        }
+    }
+
+    if opts.program.is_none() {
+        return Err("no program given".into());
+    }
+
+    Ok(opts)
+}
+
+fn encode_input(inputs: &[String], ascii: bool) -> Result<Vec<i64>, Box<dyn Error>> {
+    let text = inputs.concat();
+    if ascii {
+        return Ok(text.chars().map(|x| x as i64).collect());
+    }
+
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|x| !x.is_empty())
+        .map(|x| Ok(x.parse::<i64>()?))
+        .collect()
+}
+
+fn write_output(out: i64, ascii: bool) {
+    if ascii && (0..128).contains(&out) {
+        print!("{}", out as u8 as char);
+    } else {
+        println!("{}", out);
+    }
+}
+
+fn execute(comp: &mut IntCodeComputer, ascii: bool) -> Signal {
+    loop {
+        match comp.run() {
+            Signal::ProducedOutput => write_output(comp.get_output().unwrap(), ascii),
+            s => return s,
+        }
+    }
+}
+
+fn serve(comp: IntCodeComputer, addr: &str) -> Result<IntCodeComputer, Box<dyn Error>> {
+    let mut server = DebugServer::new(comp);
+    eprintln!("debug server listening on {}", addr);
+
+    #[cfg(unix)]
+    {
+        if let Some(path) = addr.strip_prefix("unix:") {
+            server.serve_unix(&UnixListener::bind(path)?)?;
+            return Ok(server.into_computer());
+        }
+    }
+
+    server.serve_tcp(&TcpListener::bind(addr)?)?;
+    Ok(server.into_computer())
+}
+
+// a machine with the program loaded, the input fed and the patches applied
+fn load(program: &str, opts: &Options) -> Result<IntCodeComputer, Box<dyn Error>> {
+    let program = try_parse_program(program)?;
+    let input = encode_input(&opts.inputs, opts.ascii)?;
+
+    let mut comp = get_computer(&program, input);
+    for &(addr, value) in opts.patches.iter() {
+        comp.store_value_at_pos(addr, value);
+    }
+    if opts.self_modification {
+        comp.track_self_modification(false);
+    }
+    Ok(comp)
+}
+
+fn run(opts: &Options) -> Result<i32, Box<dyn Error>> {
+    let path = opts.program.as_ref().unwrap();
+    let mut comp = load(&fs::read_to_string(path)?, opts)?;
+
+    let signal = if let Some(addr) = opts.serve.as_ref() {
+        comp = serve(comp, addr)?;
+        Signal::None
+    } else {
+        match panic::catch_unwind(AssertUnwindSafe(|| execute(&mut comp, opts.ascii))) {
+            Ok(s) => s,
+            Err(_) => return Ok(EXIT_ERROR), // the panic message has already been printed
+        }
+    };
+    io::stdout().flush()?;
+
+    if opts.dump_memory {
+        let mem = comp
+            .memory()
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>();
+        println!("{}", mem.join(","));
+    }
+
+    let (state, code) = match signal {
+        Signal::NeedsInput => ("waiting for input", EXIT_NEEDS_INPUT),
+        Signal::None => ("detached from the debugger", EXIT_HALT),
+        _ => ("halted", EXIT_HALT),
+    };
+
+    if opts.print_state {
+        eprintln!(
+            "{} (ip: {}, relative base: {})",
+            state,
+            comp.ip(),
+            comp.relative_base()
+        );
+    }
+
+    if opts.self_modification {
+        eprintln!("self modifications (site: [target] old -> new):");
+        for m in comp.self_modifications() {
+            eprintln!("{}", m);
+        }
+    }
+
+    Ok(code)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let opts = match parse_args(&args) {
+        Ok(opts) => opts,
+        Err(e) => {
+            eprintln!("intcode: {}\n\n{}", e, USAGE);
+            process::exit(EXIT_ERROR);
+        }
+    };
+
+    let code = match run(&opts) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("intcode: {}", e);
+            EXIT_ERROR
+        }
+    };
+
+    process::exit(code);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{execute, load, parse_args, parse_patch};
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_args() {
+        let opts = parse_args(&args("-a -i hi --patch 1=2 -p 3=-4 -m prog.txt")).unwrap();
+        assert_eq!(opts.program.as_deref(), Some("prog.txt"));
+        assert_eq!(opts.inputs, vec!["hi\n".to_string()]);
+        assert!(opts.ascii && opts.dump_memory && !opts.print_state);
+        assert_eq!(opts.patches, vec![(1, 2), (3, -4)]);
+
+        assert!(parse_args(&args("-i")).is_err());
+        assert!(parse_args(&args("--bogus prog.txt")).is_err());
+        assert!(parse_args(&args("one.txt two.txt")).is_err());
+        assert!(parse_args(&args("-m")).is_err());
+    }
+
+    #[test]
+    fn parses_patches() {
+        assert_eq!(parse_patch("12 = 7").unwrap(), (12, 7));
+        assert!(parse_patch("12").is_err());
+        assert!(parse_patch("x=1").is_err());
+        assert!(parse_patch("-1=1").is_err());
+    }
+
+    #[test]
+    fn patches_before_running() {
+        // stores the sum of the values at 7 and 8 at 0
+        let program = "1,7,8,0,99,0,0,0,0";
+        let opts = parse_args(&args("-p 7=20 -p 8=22 prog.txt")).unwrap();
+        let mut comp = load(program, &opts).unwrap();
+        execute(&mut comp, false);
+        assert_eq!(comp.memory()[0], 42);
+
+        assert!(load("1,5,x,0", &opts).is_err());
+    }
+}
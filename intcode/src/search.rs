@@ -0,0 +1,216 @@
+use crate::{get_computer, IntCodeComputer, Signal};
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+// Brute forces values for a set of memory addresses, running every combination
+// of values on its own machine and keeping the ones accepted by an objective.
+//
+//     let found = Search::new(&program)
+//         .patch(1, 0..100)
+//         .patch(2, 0..100)
+//         .limit(1)
+//         .run(|comp, _outputs| comp.memory()[0] == 19690720);
+//
+// Every match is the list of values assigned to the patched addresses, in the
+// order the patches were added. Matches are returned in the order the
+// combinations are enumerated (the last patch varies fastest), so a search with
+// a limit returns the same matches as a sequential search would.
+pub struct Search<'a> {
+    program: &'a [i64],
+    input: Vec<i64>,
+    patches: Vec<(i64, Range<i64>)>,
+    limit: Option<usize>,
+    max_ticks: Option<usize>,
+    threads: usize,
+}
+
+impl<'a> Search<'a> {
+    pub fn new(program: &'a [i64]) -> Self {
+        Search {
+            program,
+            input: vec![],
+            patches: vec![],
+            limit: None,
+            max_ticks: None,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+
+    pub fn input(mut self, input: Vec<i64>) -> Self {
+        self.input = input;
+        self
+    }
+
+    pub fn patch(mut self, addr: i64, values: Range<i64>) -> Self {
+        self.patches.push((addr, values));
+        self
+    }
+
+    // stop the search as soon as this many matches have been found
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    // give up on a trial that hasn't stopped after this many instructions
+    pub fn max_ticks(mut self, max_ticks: usize) -> Self {
+        self.max_ticks = Some(max_ticks);
+        self
+    }
+
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    // saturates, a search that big never finishes anyway
+    fn num_trials(&self) -> usize {
+        self.patches
+            .iter()
+            .map(|(_, r)| r.end.saturating_sub(r.start).max(0) as usize)
+            .fold(1, usize::saturating_mul)
+    }
+
+    fn assignment(&self, mut trial: usize) -> Vec<i64> {
+        let mut values = vec![0; self.patches.len()];
+        for (i, (_, range)) in self.patches.iter().enumerate().rev() {
+            let len = (range.end - range.start) as usize;
+            values[i] = range.start + (trial % len) as i64;
+            trial /= len;
+        }
+        values
+    }
+
+    // runs the machine till it halts or waits for input that will never come,
+    // returns None if it takes too long or crashes. a crash still prints its
+    // panic message.
+    fn run_trial(&self, values: &[i64]) -> Option<(IntCodeComputer, Vec<i64>)> {
+        let mut comp = get_computer(self.program, self.input.clone());
+        for ((addr, _), value) in self.patches.iter().zip(values) {
+            comp.store_value_at_pos(*addr, *value);
+        }
+
+        let mut outputs = vec![];
+        let mut ticks = 0;
+        let finished = panic::catch_unwind(AssertUnwindSafe(|| loop {
+            if self.max_ticks.is_some_and(|max| ticks >= max) {
+                return false;
+            }
+            ticks += 1;
+            match comp.tick() {
+                Signal::ProducedOutput => outputs.push(comp.get_output().unwrap()),
+                Signal::Halt | Signal::NeedsInput => return true,
+                Signal::Trap | Signal::None => {}
+            }
+        }));
+
+        match finished {
+            Ok(true) => Some((comp, outputs)),
+            _ => None,
+        }
+    }
+
+    pub fn run<F>(&self, objective: F) -> Vec<Vec<i64>>
+    where
+        F: Fn(&IntCodeComputer, &[i64]) -> bool + Sync,
+    {
+        let num_trials = self.num_trials();
+        let next_trial = AtomicUsize::new(0);
+        let done = AtomicBool::new(false);
+        let found: Mutex<Vec<(usize, Vec<i64>)>> = Mutex::new(vec![]);
+
+        thread::scope(|s| {
+            for _ in 0..self.threads {
+                s.spawn(|| {
+                    while !done.load(Ordering::Relaxed) {
+                        let trial = next_trial.fetch_add(1, Ordering::Relaxed);
+                        if trial >= num_trials {
+                            break;
+                        }
+
+                        let values = self.assignment(trial);
+                        let matched = match self.run_trial(&values) {
+                            Some((comp, outputs)) => objective(&comp, &outputs),
+                            None => false,
+                        };
+                        if !matched {
+                            continue;
+                        }
+
+                        let mut found = found.lock().unwrap();
+                        found.push((trial, values));
+                        if self.limit.is_some_and(|limit| found.len() >= limit) {
+                            done.store(true, Ordering::Relaxed);
+                        }
+                    }
+                });
+            }
+        });
+
+        // trials are handed out in order and every thread finishes the one it
+        // picked up, so everything before the last match has been checked
+        let mut found = found.into_inner().unwrap();
+        found.sort_by_key(|(trial, _)| *trial);
+        if let Some(limit) = self.limit {
+            found.truncate(limit);
+        }
+        found.into_iter().map(|(_, values)| values).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Search;
+
+    // adds or multiplies two values from the program, opcode 0 crashes
+    const PROGRAM: [i64; 5] = [1, 0, 0, 0, 99];
+
+    fn search(threads: usize) -> Search<'static> {
+        Search::new(&PROGRAM)
+            .patch(0, 0..3)
+            .patch(1, 0..5)
+            .patch(2, 0..5)
+            .threads(threads)
+    }
+
+    #[test]
+    fn matches_in_order() {
+        let all = vec![vec![1, 2, 2], vec![2, 0, 0], vec![2, 0, 2], vec![2, 2, 2]];
+        for threads in [1, 4].iter() {
+            let found = search(*threads).run(|comp, _| comp.memory()[0] == 4);
+            assert_eq!(found, all);
+
+            for limit in 1..=5 {
+                let found = search(*threads)
+                    .limit(limit)
+                    .run(|comp, _| comp.memory()[0] == 4);
+                assert_eq!(found, all[..limit.min(all.len())].to_vec());
+            }
+        }
+    }
+
+    #[test]
+    fn gives_up_on_long_trials() {
+        // jumps to itself forever when the patched value is nonzero
+        let program = [1105, 0, 0, 99];
+        let found = Search::new(&program)
+            .patch(1, 0..3)
+            .max_ticks(100)
+            .run(|_, _| true);
+        assert_eq!(found, vec![vec![0]]);
+    }
+
+    #[test]
+    fn counts_huge_searches() {
+        let big = || {
+            Search::new(&PROGRAM)
+                .patch(0, 0..i64::MAX)
+                .patch(1, 0..i64::MAX)
+        };
+        assert_eq!(big().num_trials(), usize::MAX);
+        assert_eq!(big().patch(2, 0..0).num_trials(), 0);
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt;
+
+// a write into memory that has already been executed as code
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SelfModification {
+    // address of the instruction doing the write, None if the write came from
+    // outside the machine through `store_value_at_pos`
+    pub site: Option<usize>,
+    pub target: usize,
+    pub old: i64,
+    pub new: i64,
+}
+
+impl fmt::Display for SelfModification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.site {
+            Some(site) => write!(f, "{:>6}: ", site)?,
+            None => write!(f, "{:>6}: ", "extern")?,
+        }
+        write!(f, "[{}] {} -> {}", self.target, self.old, self.new)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct SmcTracker {
+    executed: Vec<bool>,
+    trap: bool,
+    trapped: bool,
+    report: Vec<SelfModification>,
+}
+
+impl SmcTracker {
+    pub fn new(trap: bool) -> Self {
+        SmcTracker {
+            trap,
+            ..Default::default()
+        }
+    }
+
+    pub fn mark_executed(&mut self, addr: usize) {
+        if self.executed.len() <= addr {
+            self.executed.resize(addr + 1, false);
+        }
+        self.executed[addr] = true;
+    }
+
+    pub fn was_executed(&self, addr: usize) -> bool {
+        addr < self.executed.len() && self.executed[addr]
+    }
+
+    pub fn check_write(&mut self, site: Option<usize>, target: usize, old: i64, new: i64) {
+        if !self.was_executed(target) {
+            return;
+        }
+        self.report.push(SelfModification {
+            site,
+            target,
+            old,
+            new,
+        });
+        self.trapped |= self.trap && site.is_some();
+    }
+
+    pub fn take_trap(&mut self) -> bool {
+        let trapped = self.trapped;
+        self.trapped = false;
+        trapped
+    }
+
+    pub fn report(&self) -> &[SelfModification] {
+        &self.report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SelfModification;
+    use crate::{get_computer, Signal};
+
+    // writes 11 to the data at 9, then overwrites the first instruction
+    const PROGRAM: [i64; 10] = [1101, 5, 6, 9, 1101, 1, 1, 0, 99, 0];
+
+    #[test]
+    fn reports_writes_over_code() {
+        let mut comp = get_computer(&PROGRAM, vec![]);
+        comp.track_self_modification(false);
+        assert!(comp.run() == Signal::Halt);
+
+        let rewrite = SelfModification {
+            site: Some(4),
+            target: 0,
+            old: 1101,
+            new: 2,
+        };
+        assert_eq!(comp.self_modifications(), &[rewrite]);
+        assert_eq!(rewrite.to_string(), "     4: [0] 1101 -> 2");
+
+        // writes from outside count too, but never as a site
+        comp.store_value_at_pos(8, 98);
+        assert_eq!(comp.self_modifications()[1].site, None);
+        assert_eq!(
+            comp.self_modifications()[1].to_string(),
+            "extern: [8] 99 -> 98"
+        );
+    }
+
+    #[test]
+    fn traps_after_the_write() {
+        let mut comp = get_computer(&PROGRAM, vec![]);
+        comp.track_self_modification(true);
+        assert!(comp.tick() == Signal::None);
+        assert!(comp.tick() == Signal::Trap);
+        assert_eq!(comp.memory()[0], 2);
+        assert!(comp.run() == Signal::Halt);
+
+        // only writes by the program itself trap
+        comp.store_value_at_pos(0, 1101);
+        assert_eq!(comp.self_modifications().len(), 2);
+        comp.store_value_at_pos(9, 99);
+        assert!(comp.tick() == Signal::Halt);
+    }
+}
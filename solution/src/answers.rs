@@ -0,0 +1,128 @@
+use crate::Solution;
+use std::fs;
+
+// Recorded answers live next to the input in `dayN/answers`, one section per
+// part so multi-line answers (the rendered images) are stored as they are:
+//
+//     == part 1
+//     1224
+//     == part 2
+//     **** ***  ...
+const HEADER: &str = "== part ";
+
+pub fn parse(text: &str) -> Vec<(usize, String)> {
+    let mut answers: Vec<(usize, String)> = vec![];
+    for line in text.lines() {
+        if let Some(part) = line.strip_prefix(HEADER) {
+            let part = part.trim().parse().expect("bad part number in answers");
+            answers.push((part, String::new()));
+        } else if let Some((_, answer)) = answers.last_mut() {
+            if !answer.is_empty() {
+                answer.push('\n');
+            }
+            answer.push_str(line);
+        }
+    }
+    answers
+}
+
+pub fn format(answers: &[(usize, String)]) -> String {
+    let mut text = String::new();
+    for (part, answer) in answers.iter() {
+        text.push_str(&format!("{}{}\n", HEADER, part));
+        text.push_str(answer.trim_end_matches('\n'));
+        text.push('\n');
+    }
+    text
+}
+
+// replaces the recorded answer for `part`, keeping the parts sorted
+pub fn update(answers: &mut Vec<(usize, String)>, part: usize, answer: &str) {
+    let answer = answer.trim_end_matches('\n').to_string();
+    match answers.iter_mut().find(|(p, _)| *p == part) {
+        Some(entry) => entry.1 = answer,
+        None => answers.push((part, answer)),
+    }
+    answers.sort_by_key(|(p, _)| *p);
+}
+
+// the answer recorded for `part` if it isn't `answer`, recording it would
+// hide a change in the result
+pub fn conflict<'a>(answers: &'a [(usize, String)], part: usize, answer: &str) -> Option<&'a str> {
+    let answer = answer.trim_end_matches('\n');
+    answers
+        .iter()
+        .find(|(p, recorded)| *p == part && recorded != answer)
+        .map(|(_, recorded)| recorded.as_str())
+}
+
+// runs one part of a day against `dir/input` and panics if the answer differs
+// from the one recorded in `dir/answers`. used by `answer_tests!`.
+pub fn check<S: Solution>(dir: &str, part: usize) {
+    let input = fs::read_to_string(format!("{}/input", dir)).unwrap();
+    let recorded = fs::read_to_string(format!("{}/answers", dir)).unwrap_or_default();
+    let expected = parse(&recorded)
+        .into_iter()
+        .find(|(p, _)| *p == part)
+        .map(|(_, answer)| answer)
+        .unwrap_or_else(|| panic!("no answer recorded for part {}, run `aoc record`", part));
+
+    let report = crate::run::<S>(&input, Some(part));
+    let answer = report.answers[0].answer.trim_end_matches('\n');
+    assert!(
+        answer == expected,
+        "part {} changed\n  recorded: {}\n  got:      {}\nrun `aoc record` if this is intended",
+        part,
+        expected,
+        answer
+    );
+}
+
+// generates a `#[test]` per part that checks the day's answers against the
+// ones recorded in its `answers` file. takes the parts to check, both by
+// default.
+#[macro_export]
+macro_rules! answer_tests {
+    ($day:ident) => {
+        $crate::answer_tests!($day: part1, part2);
+    };
+    ($day:ident: $($part:ident),+) => {
+        #[cfg(test)]
+        mod answer_tests {
+            $(
+                #[test]
+                fn $part() {
+                    let part = stringify!($part)[4..].parse().unwrap();
+                    $crate::answers::check::<super::$day>(env!("CARGO_MANIFEST_DIR"), part);
+                }
+            )+
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{conflict, format, parse, update};
+
+    #[test]
+    fn round_trip() {
+        let mut answers = vec![(2, "#  #\n####\n".to_string())];
+        update(&mut answers, 1, "42");
+
+        let text = format(&answers);
+        assert_eq!(text, "== part 1\n42\n== part 2\n#  #\n####\n");
+        assert_eq!(
+            parse(&text),
+            vec![(1, "42".to_string()), (2, "#  #\n####".to_string())]
+        );
+    }
+
+    #[test]
+    fn conflicts() {
+        let answers = vec![(1, "42".to_string()), (2, "#  #\n####".to_string())];
+        assert_eq!(conflict(&answers, 1, "42"), None);
+        assert_eq!(conflict(&answers, 2, "#  #\n####\n"), None);
+        assert_eq!(conflict(&answers, 1, "41"), Some("42"));
+        assert_eq!(conflict(&[], 1, "41"), None);
+    }
+}
@@ -0,0 +1,63 @@
+pub mod answers;
+
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+// A day's puzzle. The input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+pub struct Answer {
+    pub part: usize,
+    pub answer: String,
+    pub time: Duration,
+}
+
+pub struct Report {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+// type erased `run` so solutions for different days can live in one table
+pub type Runner = fn(&str, Option<usize>) -> Report;
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+// parses the input and runs both parts, or only `part` if given
+pub fn run<S: Solution>(input: &str, part: Option<usize>) -> Report {
+    let (parsed, parse_time) = timed(|| S::parse(input));
+
+    let mut answers = vec![];
+    if part.is_none() || part == Some(1) {
+        let (answer, time) = timed(|| S::part1(&parsed).to_string());
+        answers.push(Answer {
+            part: 1,
+            answer,
+            time,
+        });
+    }
+    if part.is_none() || part == Some(2) {
+        let (answer, time) = timed(|| S::part2(&parsed).to_string());
+        answers.push(Answer {
+            part: 2,
+            answer,
+            time,
+        });
+    }
+
+    Report {
+        parse_time,
+        answers,
+    }
+}